@@ -0,0 +1,61 @@
+//! Parsing and both parts of every day, on the samples that have answers in `answers.toml` (for the
+//! parts that make sense on them) and on the real input when it's there.
+//!
+//! ```text
+//! cargo bench -p aoc                             # every day
+//! cargo bench -p aoc -- day-06                   # a single day
+//! cargo bench -p aoc -- --save-baseline before   # keep the numbers before a change...
+//! cargo bench -p aoc -- --baseline before        # ...and compare the numbers after it against them
+//! ```
+use std::{fs, hint::black_box, path::{Path, PathBuf}};
+
+use aoc::{answers::Answers, days::{Day, DAYS}};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+fn workspace_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(name)
+}
+
+/// Inputs to run for `day`, with the parts to run on each of them.
+fn inputs(day: &Day, answers: &Answers) -> Vec<(String, Vec<u8>)> {
+    let mut inputs: Vec<(String, Vec<u8>)> = answers.samples(&day.name()).into_iter().map(|input_name| {
+        let expected = answers.get(input_name).unwrap();
+        let parts = [1, 2].into_iter().filter(|&part| expected.part(part).is_some()).collect();
+        (input_name.to_string(), parts)
+    }).collect();
+    inputs.push((day.name(), vec![1, 2]));
+    inputs
+}
+
+fn bench_days(c: &mut Criterion) {
+    let answers = Answers::load(&workspace_path("answers.toml")).unwrap();
+    DAYS.iter().for_each(|day| {
+        let mut group = c.benchmark_group(day.name());
+        // some parts take seconds, the minimum sample size keeps a full run reasonable
+        group.sample_size(10);
+        inputs(day, &answers).into_iter().for_each(|(input_name, parts)| {
+            let Ok(input) = fs::read_to_string(workspace_path("input").join(&input_name)) else {
+                return;
+            };
+            let solution = match (day.parse)(&input) {
+                Ok(solution) => solution,
+                Err(error) => {
+                    eprintln!("skipping {input_name}, invalid input: {error}");
+                    return;
+                },
+            };
+            group.bench_with_input(BenchmarkId::new("parse", &input_name), &input, |b, input| {
+                b.iter(|| (day.parse)(black_box(input)))
+            });
+            parts.iter().for_each(|&part| {
+                group.bench_with_input(BenchmarkId::new(format!("part{part}"), &input_name), &part, |b, &part| {
+                    b.iter(|| solution.part(black_box(part)))
+                });
+            });
+        });
+        group.finish();
+    });
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
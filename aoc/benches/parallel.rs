@@ -0,0 +1,45 @@
+//! Part 2 of the days with a `parallel` feature, on one thread and on every core, so that the
+//! speedup shows side by side. Inputs are generated, the real ones aren't needed.
+//!
+//! ```text
+//! cargo bench -p aoc --features parallel --bench parallel
+//! ```
+use std::hint::black_box;
+
+use common::Solution;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use generator::find_generator;
+use rayon::{current_num_threads, ThreadPool, ThreadPoolBuilder};
+
+fn bench_day<S: Solution + Sync>(c: &mut Criterion, day: u8, pools: &[(usize, ThreadPool)]) {
+    let generator = find_generator(day).unwrap();
+    let solution = S::parse(&generator.generate(0, generator.default_size)).unwrap();
+
+    // the thread count must never change the answer
+    let answers: Vec<String> = pools.iter().map(|(_, pool)| pool.install(|| solution.part2().to_string())).collect();
+    assert!(answers.windows(2).all(|pair| pair[0] == pair[1]), "day {day}: {answers:?}");
+
+    let mut group = c.benchmark_group(format!("day-{day:02}"));
+    group.sample_size(10);
+    pools.iter().for_each(|(threads, pool)| {
+        group.bench_with_input(BenchmarkId::new("part2", format!("{threads} threads")), pool, |b, pool| {
+            b.iter(|| pool.install(|| black_box(solution.part2())))
+        });
+    });
+    group.finish();
+}
+
+fn bench_parallel(c: &mut Criterion) {
+    let mut threads = vec![1, current_num_threads()];
+    threads.dedup();
+    let pools: Vec<(usize, ThreadPool)> = threads.into_iter().map(|threads| {
+        (threads, ThreadPoolBuilder::new().num_threads(threads).build().unwrap())
+    }).collect();
+    bench_day::<day_06::LabMap>(c, 6, &pools);
+    bench_day::<day_07::Equations>(c, 7, &pools);
+    bench_day::<day_19::Onsen>(c, 19, &pools);
+    bench_day::<day_20::Cpu>(c, 20, &pools);
+}
+
+criterion_group!(benches, bench_parallel);
+criterion_main!(benches);
@@ -0,0 +1,98 @@
+use std::{collections::BTreeMap, fmt, fs, io::ErrorKind, path::Path};
+
+use serde::Deserialize;
+
+/// An answer as written in `answers.toml`, either a bare number or a string.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum Expected {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expected::Number(number) => write!(f, "{number}"),
+            Expected::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct InputAnswers {
+    part1: Option<Expected>,
+    part2: Option<Expected>,
+}
+
+impl InputAnswers {
+    pub fn part(&self, part: u8) -> Option<&Expected> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// Known-good answers, keyed by the name of the input file in `input/`.
+#[derive(Debug, Default)]
+pub struct Answers {
+    inputs: BTreeMap<String, InputAnswers>,
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let inputs = toml::from_str(text).map_err(|error| error.to_string())?;
+        Ok(Self { inputs })
+    }
+
+    /// A missing manifest just means nothing was recorded yet.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|error| format!("invalid {}: {error}", path.display())),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(format!("cannot read {}: {error}", path.display())),
+        }
+    }
+
+    pub fn get(&self, input: &str) -> Option<&InputAnswers> {
+        self.inputs.get(input)
+    }
+
+    /// Sample inputs of a day with recorded answers, e.g. `day-12-test` and `day-12-test-2`.
+    pub fn samples(&self, day_name: &str) -> Vec<&str> {
+        let prefix = format!("{day_name}-test");
+        self.inputs.keys().filter(|input| input.starts_with(&prefix)).map(|input| input.as_str()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::{Answers, Expected};
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("\
+[day-17]
+part1 = \"4,6,3,5,6,3,5,2,1,0\"
+part2 = 117440
+
+[day-17-test]
+part1 = \"4,6,3,5,6,3,5,2,1,0\"
+").unwrap();
+        let day_17 = answers.get("day-17").unwrap();
+        assert_eq!(day_17.part(1), Some(&Expected::Text("4,6,3,5,6,3,5,2,1,0".to_string())));
+        assert_eq!(day_17.part(2).map(|answer| answer.to_string()), Some("117440".to_string()));
+        assert_eq!(answers.get("day-17-test").unwrap().part(2), None);
+        assert_eq!(answers.samples("day-17"), vec!["day-17-test"]);
+        assert!(answers.get("day-18").is_none());
+    }
+
+    #[test]
+    fn test_unknown_part() {
+        let error = Answers::parse("[day-01]\npart3 = 12\n").unwrap_err();
+        assert!(error.contains("unknown field `part3`"), "{error}");
+    }
+}
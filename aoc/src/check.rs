@@ -0,0 +1,164 @@
+use std::{
+    io::ErrorKind,
+    panic::{self, AssertUnwindSafe},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use common::{input::InputSource, Answer};
+
+use crate::{
+    answers::{Answers, Expected},
+    days::Day,
+    table::{format_elapsed, Align, Table},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail(Expected),
+    /// No input, or no known-good answer to compare with
+    Missing(String),
+    /// The input is missing or invalid, or the solver failed or panicked
+    Error(String),
+}
+
+pub struct Outcome {
+    day: u8,
+    part: u8,
+    input: String,
+    answer: Option<String>,
+    status: Status,
+    elapsed: Option<Duration>,
+}
+
+impl Outcome {
+    fn unsolved(day: u8, part: u8, input: &str, status: Status) -> Self {
+        Self { day, part, input: input.to_string(), answer: None, status, elapsed: None }
+    }
+
+    fn cells(&self) -> Vec<String> {
+        let (status, details) = match &self.status {
+            Status::Pass => ("pass", String::new()),
+            Status::Fail(expected) => ("FAIL", format!("expected {expected}")),
+            Status::Missing(reason) => ("missing", reason.clone()),
+            Status::Error(reason) => ("FAIL", reason.clone()),
+        };
+        vec![
+            self.day.to_string(),
+            self.part.to_string(),
+            self.input.clone(),
+            status.to_string(),
+            self.answer.clone().unwrap_or_else(|| "-".to_string()),
+            format_elapsed(self.elapsed),
+            details,
+        ]
+    }
+}
+
+pub fn compare(answer: &str, expected: Option<&Expected>) -> Status {
+    match expected {
+        None => Status::Missing("no recorded answer".to_string()),
+        Some(expected) if expected.to_string() == answer => Status::Pass,
+        Some(expected) => Status::Fail(expected.clone()),
+    }
+}
+
+/// Runs `f`, turning a panic into an error message instead of aborting the whole check or run.
+pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        let message = payload.downcast_ref::<&str>().map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown reason".to_string());
+        format!("panicked: {message}")
+    })
+}
+
+fn unsolved(day: &Day, parts: &[u8], input_name: &str, status: Status) -> Vec<Outcome> {
+    parts.iter().map(|&part| Outcome::unsolved(day.number, part, input_name, status.clone())).collect()
+}
+
+fn check_input(day: &Day, input_name: &str, parts: &[u8], answers: &Answers) -> Vec<Outcome> {
+    let input = match InputSource::from_arg(input_name, None).read() {
+        Ok(input) => input,
+        Err(error) => {
+            let status = match error.error.kind() {
+                ErrorKind::NotFound => Status::Missing("no input".to_string()),
+                _ => Status::Error(error.to_string()),
+            };
+            return unsolved(day, parts, input_name, status);
+        },
+    };
+    let solution = match catch(|| (day.parse)(&input)) {
+        Ok(Ok(solution)) => solution,
+        Ok(Err(error)) => return unsolved(day, parts, input_name, Status::Error(format!("invalid input: {error}"))),
+        Err(reason) => return unsolved(day, parts, input_name, Status::Error(reason)),
+    };
+    let expected = answers.get(input_name);
+    parts.iter().map(|&part| {
+        let start = Instant::now();
+        match catch(|| solution.part(part)) {
+            Ok(Answer::Error(message)) => Outcome::unsolved(day.number, part, input_name, Status::Error(message)),
+            Ok(answer) => {
+                let elapsed = start.elapsed();
+                let answer = answer.to_string();
+                let status = compare(&answer, expected.and_then(|expected| expected.part(part)));
+                Outcome { day: day.number, part, input: input_name.to_string(), answer: Some(answer), status, elapsed: Some(elapsed) }
+            },
+            Err(reason) => Outcome::unsolved(day.number, part, input_name, Status::Error(reason)),
+        }
+    }).collect()
+}
+
+/// Checks every part of `days` against the real inputs, or with `samples` against every sample input
+/// that has answers recorded (only for the parts it has answers for).
+pub fn check(days: &[&Day], parts: &[u8], samples: bool, answers: &Answers) -> Vec<Outcome> {
+    days.iter().flat_map(|day| {
+        if !samples {
+            return check_input(day, &day.name(), parts, answers);
+        }
+        answers.samples(&day.name()).into_iter().flat_map(|input_name| {
+            let expected = answers.get(input_name).unwrap();
+            let parts: Vec<u8> = parts.iter().copied().filter(|&part| expected.part(part).is_some()).collect();
+            check_input(day, input_name, &parts, answers)
+        }).collect()
+    }).collect()
+}
+
+pub fn report(outcomes: &[Outcome]) -> ExitCode {
+    let mut table = Table::new(&[
+        ("Day", Align::Right),
+        ("Part", Align::Right),
+        ("Input", Align::Left),
+        ("Status", Align::Left),
+        ("Answer", Align::Left),
+        ("Time", Align::Right),
+        ("Details", Align::Left),
+    ]);
+    outcomes.iter().for_each(|outcome| table.push(outcome.cells()));
+    table.print();
+
+    let count = |predicate: fn(&Status) -> bool| outcomes.iter().filter(|outcome| predicate(&outcome.status)).count();
+    let passed = count(|status| matches!(status, Status::Pass));
+    let missing = count(|status| matches!(status, Status::Missing(_)));
+    let failed = outcomes.len() - passed - missing;
+    println!("\n{passed} passed, {failed} failed, {missing} missing");
+    match failed {
+        0 => ExitCode::SUCCESS,
+        _ => ExitCode::FAILURE,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answers::Expected, check::{compare, Status}};
+
+    #[test]
+    fn test_compare() {
+        assert_eq!(compare("11", Some(&Expected::Number(11))), Status::Pass);
+        assert_eq!(compare("4,6,3", Some(&Expected::Text("4,6,3".to_string()))), Status::Pass);
+        assert_eq!(compare("12", Some(&Expected::Number(11))), Status::Fail(Expected::Number(11)));
+        assert_eq!(compare("not found", Some(&Expected::Number(11))), Status::Fail(Expected::Number(11)));
+        assert_eq!(compare("11", None), Status::Missing("no recorded answer".to_string()));
+    }
+}
@@ -0,0 +1,67 @@
+use common::{solution::parse_boxed, ParseError, Solution};
+
+pub struct Day {
+    pub number: u8,
+    pub parse: fn(&str) -> Result<Box<dyn Solution>, ParseError>,
+}
+
+impl Day {
+    pub fn name(&self) -> String {
+        format!("day-{:02}", self.number)
+    }
+}
+
+pub fn find_day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+pub const DAYS: [Day; 21] = [
+    Day { number: 1, parse: parse_boxed::<day_01::LocationLists> },
+    Day { number: 2, parse: parse_boxed::<day_02::Reports> },
+    Day { number: 3, parse: parse_boxed::<day_03::CorruptedMemory> },
+    Day { number: 4, parse: parse_boxed::<day_04::WordSearch> },
+    Day { number: 5, parse: parse_boxed::<day_05::Instructions> },
+    Day { number: 6, parse: parse_boxed::<day_06::LabMap> },
+    Day { number: 7, parse: parse_boxed::<day_07::Equations> },
+    Day { number: 8, parse: parse_boxed::<day_08::AntennaMap> },
+    Day { number: 9, parse: parse_boxed::<day_09::FileSystem> },
+    Day { number: 10, parse: parse_boxed::<day_10::TopographicMap> },
+    Day { number: 11, parse: parse_boxed::<day_11::Arrangement> },
+    Day { number: 12, parse: parse_boxed::<day_12::Garden> },
+    Day { number: 13, parse: parse_boxed::<day_13::Machines> },
+    Day { number: 14, parse: parse_boxed::<day_14::Robots> },
+    Day { number: 15, parse: parse_boxed::<day_15::Warehouse> },
+    Day { number: 16, parse: parse_boxed::<day_16::Maze> },
+    Day { number: 17, parse: parse_boxed::<day_17::Computer> },
+    Day { number: 18, parse: parse_boxed::<day_18::FallingBytes> },
+    Day { number: 19, parse: parse_boxed::<day_19::Onsen> },
+    Day { number: 20, parse: parse_boxed::<day_20::Cpu> },
+    Day { number: 21, parse: parse_boxed::<day_21::DoorCodes> },
+];
+
+#[cfg(test)]
+mod tests {
+    use generator::GENERATORS;
+    use crate::days::find_day;
+
+    #[test]
+    fn test_generated_inputs() {
+        // the smallest sizes are where the edge cases are
+        let cases = [1, 2, 3, 5].into_iter().flat_map(|size| (0..4).map(move |seed| (seed, size)));
+        cases.for_each(|(seed, size)| GENERATORS.iter().for_each(|generator| {
+            // day 18 walks its whole memory whatever the size, which is slow in a debug build
+            if generator.day == 18 && seed > 0 {
+                return;
+            }
+            let input = generator.generate(seed, size);
+            let day = find_day(generator.day).unwrap();
+            let context = format!("day {} with seed {seed} and size {size}", generator.day);
+            let solution = (day.parse)(&input).unwrap_or_else(|error| panic!("{context}: {error}"));
+            solution.part1();
+            // day 11's part 2 takes seconds in a debug build
+            if generator.day != 11 {
+                solution.part2();
+            }
+        }));
+    }
+}
@@ -0,0 +1,220 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Where the session token is read from: the `session` cookie of a logged in browser.
+pub const SESSION_VARIABLE: &str = "AOC_SESSION";
+pub const BASE_URL: &str = "https://adventofcode.com";
+const YEAR: u16 = 2024;
+// the site asks tools to throttle their requests, and inputs never change anyway
+const MIN_INTERVAL: Duration = Duration::from_secs(5);
+// remembers when the last request went out, across runs
+const LAST_REQUEST_FILE: &str = ".last-fetch";
+const USER_AGENT: &str = concat!("aoc-runner/", env!("CARGO_PKG_VERSION"), " (puzzle input fetch, cached)");
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum HttpError {
+    Status(u16),
+    Other(String),
+}
+
+/// Anything that can send a GET request, so that tests don't need the real site.
+pub trait HttpClient {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<String, HttpError>;
+}
+
+pub struct UreqClient;
+
+impl HttpClient for UreqClient {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<String, HttpError> {
+        let request = headers.iter().fold(ureq::get(url), |request, (name, value)| request.header(*name, *value));
+        let mut response = request.call().map_err(|error| match error {
+            ureq::Error::StatusCode(status) => HttpError::Status(status),
+            error => HttpError::Other(error.to_string()),
+        })?;
+        response.body_mut().read_to_string().map_err(|error| HttpError::Other(error.to_string()))
+    }
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    MissingSession,
+    Http { day: u8, error: HttpError },
+    Io { path: PathBuf, error: io::Error },
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::MissingSession => write!(f, "{SESSION_VARIABLE} is not set, copy the `session` cookie of adventofcode.com into it"),
+            FetchError::Http { day, error: HttpError::Status(400) } =>
+                write!(f, "cannot download day {day}: the site rejected the session token, is it still valid?"),
+            FetchError::Http { day, error: HttpError::Status(404) } =>
+                write!(f, "cannot download day {day}: not found, is the puzzle unlocked yet?"),
+            FetchError::Http { day, error: HttpError::Status(status) } => write!(f, "cannot download day {day}: status {status}"),
+            FetchError::Http { day, error: HttpError::Other(message) } => write!(f, "cannot download day {day}: {message}"),
+            FetchError::Io { path, error } => write!(f, "cannot write {}: {error}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Downloads inputs into `cache_dir`, named like `aoc run` expects them, and never twice.
+pub struct Fetcher<C> {
+    client: C,
+    cache_dir: PathBuf,
+    base_url: String,
+    min_interval: Duration,
+}
+
+impl<C: HttpClient> Fetcher<C> {
+    pub fn new(client: C, cache_dir: impl Into<PathBuf>) -> Self {
+        Self { client, cache_dir: cache_dir.into(), base_url: BASE_URL.to_string(), min_interval: MIN_INTERVAL }
+    }
+
+    pub fn with_base_url(self, base_url: &str) -> Self {
+        Self { base_url: base_url.trim_end_matches('/').to_string(), ..self }
+    }
+
+    pub fn with_min_interval(self, min_interval: Duration) -> Self {
+        Self { min_interval, ..self }
+    }
+
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.cache_dir.join(format!("day-{day:02}"))
+    }
+
+    /// The session is only needed when the input isn't cached yet.
+    pub fn fetch(&self, day: u8, session: Option<&str>) -> Result<Fetched, FetchError> {
+        let path = self.path(day);
+        if path.exists() {
+            return Ok(Fetched::Cached(path));
+        }
+        let session = session.ok_or(FetchError::MissingSession)?;
+        fs::create_dir_all(&self.cache_dir).map_err(|error| io_error(&self.cache_dir, error))?;
+
+        self.wait_for_rate_limit();
+        let url = format!("{}/{YEAR}/day/{day}/input", self.base_url);
+        let cookie = format!("session={session}");
+        let result = self.client.get(&url, &[("Cookie", &cookie), ("User-Agent", USER_AGENT)]);
+        let last_request = self.cache_dir.join(LAST_REQUEST_FILE);
+        fs::write(&last_request, now().as_millis().to_string()).map_err(|error| io_error(&last_request, error))?;
+        let input = result.map_err(|error| FetchError::Http { day, error })?;
+
+        // write then rename, so that an interrupted download never looks cached
+        let partial = self.cache_dir.join(format!(".day-{day:02}.partial"));
+        fs::write(&partial, input).map_err(|error| io_error(&partial, error))?;
+        fs::rename(&partial, &path).map_err(|error| io_error(&path, error))?;
+        Ok(Fetched::Downloaded(path))
+    }
+
+    fn wait_for_rate_limit(&self) {
+        let last_request = fs::read_to_string(self.cache_dir.join(LAST_REQUEST_FILE)).ok()
+            .and_then(|millis| millis.trim().parse::<u64>().ok())
+            .map(Duration::from_millis);
+        if let Some(elapsed) = last_request.and_then(|last_request| now().checked_sub(last_request)) {
+            // the timestamp is truncated to milliseconds, so `elapsed` can be up to one too many
+            thread::sleep(self.min_interval.saturating_sub(elapsed.saturating_sub(Duration::from_millis(1))));
+        }
+    }
+}
+
+fn io_error(path: &Path, error: io::Error) -> FetchError {
+    FetchError::Io { path: path.to_path_buf(), error }
+}
+
+fn now() -> Duration {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, env, fs, path::PathBuf, process, thread, time::{Duration, Instant}};
+    use crate::fetch::{FetchError, Fetched, Fetcher, HttpClient, HttpError, UreqClient};
+
+    struct FakeClient {
+        requests: RefCell<Vec<String>>,
+    }
+
+    impl HttpClient for FakeClient {
+        fn get(&self, url: &str, _headers: &[(&str, &str)]) -> Result<String, HttpError> {
+            self.requests.borrow_mut().push(url.to_string());
+            match url.ends_with("/day/25/input") {
+                true => Err(HttpError::Status(404)),
+                false => Ok("1   2\n".to_string()),
+            }
+        }
+    }
+
+    fn cache_dir(name: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("aoc-fetch-{name}-{}", process::id()));
+        let _ = fs::remove_dir_all(&path);
+        path
+    }
+
+    #[test]
+    fn test_cache() {
+        let dir = cache_dir("cache");
+        let fetcher = Fetcher::new(FakeClient { requests: RefCell::new(vec![]) }, &dir).with_min_interval(Duration::ZERO);
+        assert!(matches!(fetcher.fetch(1, None), Err(FetchError::MissingSession)));
+        assert_eq!(fetcher.fetch(1, Some("token")).unwrap(), Fetched::Downloaded(dir.join("day-01")));
+        assert_eq!(fs::read_to_string(dir.join("day-01")).unwrap(), "1   2\n");
+        // cached inputs need neither a request nor a session
+        assert_eq!(fetcher.fetch(1, None).unwrap(), Fetched::Cached(dir.join("day-01")));
+        assert_eq!(*fetcher.client.requests.borrow(), vec!["https://adventofcode.com/2024/day/1/input"]);
+
+        let error = fetcher.fetch(25, Some("token")).unwrap_err();
+        assert_eq!(error.to_string(), "cannot download day 25: not found, is the puzzle unlocked yet?");
+        assert!(!dir.join("day-25").exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_rate_limit() {
+        let dir = cache_dir("rate-limit");
+        let fetcher = Fetcher::new(FakeClient { requests: RefCell::new(vec![]) }, &dir).with_min_interval(Duration::from_millis(300));
+        let start = Instant::now();
+        fetcher.fetch(1, Some("token")).unwrap();
+        fetcher.fetch(2, Some("token")).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(300));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_mock_server() {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let handle = thread::spawn(move || {
+            (0..2).map(|_| {
+                let request = server.recv().unwrap();
+                let cookie = request.headers().iter().find(|header| header.field.equiv("Cookie")).map(|header| header.value.to_string());
+                let response = match (request.url(), cookie.as_deref()) {
+                    ("/2024/day/3/input", Some("session=secret")) => tiny_http::Response::from_string("mul(2,4)\n"),
+                    _ => tiny_http::Response::from_string("Puzzle inputs differ by user.").with_status_code(400),
+                };
+                let url = request.url().to_string();
+                request.respond(response).unwrap();
+                url
+            }).collect::<Vec<String>>()
+        });
+
+        let dir = cache_dir("mock-server");
+        let fetcher = Fetcher::new(UreqClient, &dir).with_base_url(&base_url).with_min_interval(Duration::ZERO);
+        assert_eq!(fetcher.fetch(3, Some("secret")).unwrap(), Fetched::Downloaded(dir.join("day-03")));
+        assert_eq!(fs::read_to_string(dir.join("day-03")).unwrap(), "mul(2,4)\n");
+        let error = fetcher.fetch(4, Some("expired")).unwrap_err();
+        assert_eq!(error.to_string(), "cannot download day 4: the site rejected the session token, is it still valid?");
+        assert_eq!(handle.join().unwrap(), vec!["/2024/day/3/input", "/2024/day/4/input"]);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
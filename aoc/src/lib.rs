@@ -0,0 +1,6 @@
+pub mod answers;
+pub mod check;
+pub mod days;
+pub mod fetch;
+pub mod scaffold;
+pub mod table;
@@ -0,0 +1,253 @@
+use std::{env, io::ErrorKind, panic, path::{Path, PathBuf}, process::ExitCode};
+
+use aoc::{
+    answers::Answers,
+    check,
+    days::{find_day, Day, DAYS},
+    fetch::{Fetched, Fetcher, UreqClient, SESSION_VARIABLE},
+    scaffold,
+    table::{self, PhaseRow, Row},
+};
+use clap::{Parser, Subcommand};
+use common::{input::InputSource, profile, Answer};
+use generator::{find_generator, Generator};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2024 runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day, or every day with `all`, failing when any part can't be solved
+    Run {
+        /// Day number between 1 and 25, or `all`
+        #[arg(value_parser = parse_day_selection)]
+        day: DaySelection,
+        /// Only run this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file, `-` for stdin (defaults to `input/day-NN`)
+        #[arg(long)]
+        input: Option<String>,
+    },
+    /// Compare answers with the ones recorded in the answers manifest
+    Check {
+        /// Day number between 1 and 25, or `all`
+        #[arg(value_parser = parse_day_selection, default_value = "all")]
+        day: DaySelection,
+        /// Only check this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Check the sample inputs instead of the real ones
+        #[arg(long)]
+        samples: bool,
+        /// Answers manifest
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+    },
+    /// Download the input of a day, or of every day with `all`, unless it's already there
+    Fetch {
+        /// Day number between 1 and 25, solved or not, or `all` for the solved ones
+        #[arg(value_parser = parse_any_day_selection)]
+        day: DaySelection,
+        /// Where the inputs are kept
+        #[arg(long, default_value = "input")]
+        cache_dir: PathBuf,
+    },
+    /// Create the crate of a new day, from a template with stubs for parsing and both parts
+    New {
+        /// Day number between 1 and 25
+        #[arg(value_parser = parse_day_number)]
+        day: u8,
+    },
+    /// Print a random input for a day, the same one every time for a given seed and size
+    Generate {
+        /// Day number between 1 and 25
+        #[arg(value_parser = parse_generator)]
+        day: &'static Generator,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Defaults to about the size of the real input
+        #[arg(long, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+        size: Option<usize>,
+    },
+}
+
+#[derive(Clone, Copy)]
+enum DaySelection {
+    All,
+    Single(u8),
+}
+
+fn parse_day_number(value: &str) -> Result<u8, String> {
+    match value.parse::<u8>() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("expected a day number between 1 and 25, got `{value}`")),
+    }
+}
+
+fn parse_any_day_selection(value: &str) -> Result<DaySelection, String> {
+    match value {
+        "all" => Ok(DaySelection::All),
+        _ => parse_day_number(value).map(DaySelection::Single)
+            .map_err(|_| format!("expected a day number between 1 and 25 or `all`, got `{value}`")),
+    }
+}
+
+fn parse_day_selection(value: &str) -> Result<DaySelection, String> {
+    match parse_any_day_selection(value)? {
+        DaySelection::Single(day) if find_day(day).is_none() => Err(format!("day {day} is not solved yet")),
+        selection => Ok(selection),
+    }
+}
+
+fn parse_generator(value: &str) -> Result<&'static Generator, String> {
+    match value.parse::<u8>() {
+        Ok(day) => find_generator(day).ok_or_else(|| format!("there's no generator for day {day}")),
+        Err(_) => Err(format!("expected a day number, got `{value}`")),
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run { day, part, input } => run(day, part, input),
+        Command::Check { day, part, samples, answers } => check(day, part, samples, answers),
+        Command::Fetch { day, cache_dir } => fetch(day, cache_dir),
+        Command::New { day } => new(day),
+        Command::Generate { day, seed, size } => {
+            print!("{}", day.generate(seed, size.unwrap_or(day.default_size)));
+            ExitCode::SUCCESS
+        },
+    }
+}
+
+fn selected_parts(part: Option<u8>) -> Vec<u8> {
+    match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    }
+}
+
+fn run(selection: DaySelection, part: Option<u8>, input: Option<String>) -> ExitCode {
+    let parts = selected_parts(part);
+    let mut phases = vec![];
+    // panics are reported in the table, keep them from cluttering it
+    panic::set_hook(Box::new(|_| {}));
+    let rows = match selection {
+        DaySelection::Single(number) => {
+            let day = find_day(number).unwrap();
+            let source = InputSource::from_arg(&day.name(), input.as_deref());
+            match source.read() {
+                Ok(input) => solve_day(day, &parts, &input, &mut phases),
+                Err(error) => {
+                    eprintln!("error: {error}");
+                    return ExitCode::FAILURE;
+                },
+            }
+        },
+        DaySelection::All => {
+            if input.is_some() {
+                eprintln!("error: --input only makes sense for a single day");
+                return ExitCode::FAILURE;
+            }
+            DAYS.iter().flat_map(|day| {
+                match InputSource::from_arg(&day.name(), None).read() {
+                    Ok(input) => solve_day(day, &parts, &input, &mut phases),
+                    Err(error) => {
+                        let reason = match error.error.kind() {
+                            ErrorKind::NotFound => "missing input".to_string(),
+                            _ => error.to_string(),
+                        };
+                        parts.iter().map(|&part| Row::failed(day.number, part, reason.clone())).collect()
+                    },
+                }
+            }).collect()
+        },
+    };
+    let _ = panic::take_hook();
+    table::print(&rows);
+    if profile::ENABLED && !phases.is_empty() {
+        println!();
+        table::print_phases(&phases);
+    }
+    match rows.iter().any(Row::is_failed) {
+        true => ExitCode::FAILURE,
+        false => ExitCode::SUCCESS,
+    }
+}
+
+// a panicking day fails its rows, the other days still run
+fn solve_day(day: &Day, parts: &[u8], input: &str, phases: &mut Vec<PhaseRow>) -> Vec<Row> {
+    let (solution, parse) = profile::measure(|| check::catch(|| (day.parse)(input)));
+    phases.push(PhaseRow::new(day.number, "parse", parse));
+    match solution {
+        Ok(Ok(solution)) => parts.iter().map(|&part| {
+            let (answer, measurement) = profile::measure(|| check::catch(|| solution.part(part)));
+            phases.push(PhaseRow::new(day.number, &format!("part {part}"), measurement));
+            match answer {
+                Ok(Answer::Error(message)) => Row::failed(day.number, part, format!("error: {message}")),
+                Ok(answer) => Row::solved(day.number, part, answer.to_string(), measurement.elapsed),
+                Err(reason) => Row::failed(day.number, part, reason),
+            }
+        }).collect(),
+        Ok(Err(error)) => parts.iter().map(|&part| Row::failed(day.number, part, format!("invalid input: {error}"))).collect(),
+        Err(reason) => parts.iter().map(|&part| Row::failed(day.number, part, reason.clone())).collect(),
+    }
+}
+
+fn check(selection: DaySelection, part: Option<u8>, samples: bool, answers: PathBuf) -> ExitCode {
+    let answers = match Answers::load(&answers) {
+        Ok(answers) => answers,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        },
+    };
+    let days: Vec<&Day> = match selection {
+        DaySelection::Single(number) => vec![find_day(number).unwrap()],
+        DaySelection::All => DAYS.iter().collect(),
+    };
+    // panics are reported in the table, keep them from cluttering it
+    panic::set_hook(Box::new(|_| {}));
+    let outcomes = check::check(&days, &selected_parts(part), samples, &answers);
+    let _ = panic::take_hook();
+    check::report(&outcomes)
+}
+
+fn fetch(selection: DaySelection, cache_dir: PathBuf) -> ExitCode {
+    let session = env::var(SESSION_VARIABLE).ok();
+    let fetcher = Fetcher::new(UreqClient, cache_dir);
+    let days: Vec<u8> = match selection {
+        DaySelection::Single(number) => vec![number],
+        DaySelection::All => DAYS.iter().map(|day| day.number).collect(),
+    };
+    for day in days {
+        match fetcher.fetch(day, session.as_deref()) {
+            Ok(Fetched::Cached(path)) => println!("day {day}: already in {}", path.display()),
+            Ok(Fetched::Downloaded(path)) => println!("day {day}: downloaded to {}", path.display()),
+            Err(error) => {
+                eprintln!("error: {error}");
+                return ExitCode::FAILURE;
+            },
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn new(day: u8) -> ExitCode {
+    match scaffold::create_day(Path::new("."), day) {
+        Ok(files) => {
+            files.iter().for_each(|file| println!("wrote {}", file.display()));
+            println!("to run it with `aoc`, add day-{day:02} to the dependencies of aoc/Cargo.toml and to DAYS in aoc/src/days.rs");
+            ExitCode::SUCCESS
+        },
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        },
+    }
+}
@@ -0,0 +1,110 @@
+use std::{fs, path::{Path, PathBuf}};
+
+const MANIFEST: &str = include_str!("../templates/Cargo.toml.template");
+const LIB: &str = include_str!("../templates/lib.rs.template");
+const MAIN: &str = include_str!("../templates/main.rs.template");
+
+fn render(template: &str, day: u8) -> String {
+    template.replace("{day}", &format!("{day:02}")).replace("{number}", &day.to_string())
+}
+
+/// Adds `member` to the `members` list of the workspace manifest, keeping it sorted and the rest
+/// of the file untouched.
+pub fn add_member(manifest: &str, member: &str) -> Result<String, String> {
+    let start = manifest.find("members = [").ok_or("no `members` list in the workspace manifest")?;
+    let end = start + manifest[start..].find(']').ok_or("unterminated `members` list")?;
+    let entry = format!("\"{member}\"");
+    let mut offset = start + manifest[start..].find('\n').ok_or("expected one member per line")? + 1;
+    for line in manifest[offset..end].split_inclusive('\n') {
+        let existing = line.trim().trim_end_matches(',');
+        if existing == entry {
+            return Err(format!("{member} is already a workspace member"));
+        }
+        if existing > entry.as_str() {
+            break;
+        }
+        offset += line.len();
+    }
+    // the member before the new one may be the last of the list, written without a comma
+    let before = manifest[..offset].trim_end();
+    let comma = if before.ends_with([',', '[']) { "" } else { "," };
+    Ok(format!("{before}{comma}{}    {entry},\n{}", &manifest[before.len()..offset], &manifest[offset..]))
+}
+
+/// Creates the `day-NN` crate under the workspace `root`, registers it and adds an empty sample
+/// input, returning the files it wrote.
+pub fn create_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    let name = format!("day-{day:02}");
+    let crate_dir = root.join(&name);
+    if crate_dir.exists() {
+        return Err(format!("{} already exists", crate_dir.display()));
+    }
+    let manifest_path = root.join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path).map_err(|error| format!("cannot read {}: {error}", manifest_path.display()))?;
+    let manifest = add_member(&manifest, &name)?;
+
+    let mut files = vec![
+        (crate_dir.join("Cargo.toml"), render(MANIFEST, day)),
+        (crate_dir.join("src/lib.rs"), render(LIB, day)),
+        (crate_dir.join("src/main.rs"), render(MAIN, day)),
+    ];
+    // the real input is personal and comes from `aoc fetch`, only the sample is a placeholder
+    let sample = root.join(format!("input/{name}-test"));
+    if !sample.exists() {
+        files.push((sample, String::new()));
+    }
+    files.push((manifest_path, manifest));
+
+    files.iter().try_for_each(|(path, contents)| {
+        fs::create_dir_all(path.parent().unwrap())
+            .and_then(|_| fs::write(path, contents))
+            .map_err(|error| format!("cannot write {}: {error}", path.display()))
+    })?;
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+    use crate::scaffold::{add_member, create_day};
+
+    const MANIFEST: &str = "[workspace]\n\nmembers = [\n    \"aoc\",\n    \"day-01\",\n    \"day-03\",\n    \"grid\",\n]";
+
+    #[test]
+    fn test_add_member() {
+        let manifest = add_member(MANIFEST, "day-02").unwrap();
+        assert_eq!(manifest, "[workspace]\n\nmembers = [\n    \"aoc\",\n    \"day-01\",\n    \"day-02\",\n    \"day-03\",\n    \"grid\",\n]");
+        let manifest = add_member(MANIFEST, "zebra").unwrap();
+        assert!(manifest.ends_with("    \"grid\",\n    \"zebra\",\n]"), "{manifest}");
+        assert_eq!(add_member(MANIFEST, "day-03"), Err("day-03 is already a workspace member".to_string()));
+        assert!(add_member("[package]\n", "day-02").is_err());
+    }
+
+    #[test]
+    fn test_add_member_without_trailing_comma() {
+        let manifest = "[workspace]\nmembers = [\n    \"aoc\",\n    \"grid\"\n]\n";
+        assert_eq!(add_member(manifest, "zebra").unwrap(), "[workspace]\nmembers = [\n    \"aoc\",\n    \"grid\",\n    \"zebra\",\n]\n");
+        assert_eq!(add_member(manifest, "day-02").unwrap(), "[workspace]\nmembers = [\n    \"aoc\",\n    \"day-02\",\n    \"grid\"\n]\n");
+        assert_eq!(add_member("members = [\n]", "aoc").unwrap(), "members = [\n    \"aoc\",\n]");
+    }
+
+    #[test]
+    fn test_create_day() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join("Cargo.toml"), MANIFEST).unwrap();
+
+        let files = create_day(&root, 2).unwrap();
+        assert_eq!(files.len(), 5);
+        assert!(fs::read_to_string(root.join("Cargo.toml")).unwrap().contains("\"day-02\""));
+        assert!(fs::read_to_string(root.join("day-02/Cargo.toml")).unwrap().contains("name = \"day-02\""));
+        let main = fs::read_to_string(root.join("day-02/src/main.rs")).unwrap();
+        assert!(main.contains("use day_02::Puzzle;") && main.contains("run::<Puzzle>(2, "), "{main}");
+        assert!(fs::read_to_string(root.join("day-02/src/lib.rs")).unwrap().contains("input/day-02-test"));
+        assert_eq!(fs::read_to_string(root.join("input/day-02-test")).unwrap(), "");
+
+        assert_eq!(create_day(&root, 2).unwrap_err(), format!("{} already exists", root.join("day-02").display()));
+        fs::remove_dir_all(root).unwrap();
+    }
+}
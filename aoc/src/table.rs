@@ -0,0 +1,168 @@
+use std::time::Duration;
+
+use common::profile::{Bytes, Measurement};
+
+#[derive(Clone, Copy)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+pub struct Table {
+    columns: Vec<(&'static str, Align)>,
+    lines: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(columns: &[(&'static str, Align)]) -> Self {
+        Self { columns: columns.to_vec(), lines: vec![] }
+    }
+
+    pub fn push(&mut self, cells: Vec<String>) {
+        assert_eq!(cells.len(), self.columns.len(), "wrong number of cells");
+        self.lines.push(cells);
+    }
+
+    pub fn render(&self) -> String {
+        let header: Vec<String> = self.columns.iter().map(|(title, _)| title.to_string()).collect();
+        let mut widths: Vec<usize> = header.iter().map(|title| title.len()).collect();
+        self.lines.iter().for_each(|cells| {
+            cells.iter().enumerate().for_each(|(index, cell)| widths[index] = widths[index].max(cell.len()));
+        });
+
+        let format_line = |cells: &[String], separator: &str| {
+            cells.iter().zip(&self.columns).zip(&widths).map(|((cell, (_, align)), &width)| match align {
+                Align::Left => format!("{cell:<width$}"),
+                Align::Right => format!("{cell:>width$}"),
+            }).collect::<Vec<String>>().join(separator)
+        };
+        let dashes: Vec<String> = widths.iter().map(|&width| "-".repeat(width)).collect();
+
+        let mut output = format_line(&header, " | ").trim_end().to_string();
+        output.push('\n');
+        output.push_str(&format_line(&dashes, "-+-"));
+        output.push('\n');
+        self.lines.iter().for_each(|cells| {
+            output.push_str(format_line(cells, " | ").trim_end());
+            output.push('\n');
+        });
+        output
+    }
+
+    pub fn print(&self) {
+        print!("{}", self.render());
+    }
+}
+
+pub struct Row {
+    day: u8,
+    part: u8,
+    answer: String,
+    elapsed: Option<Duration>,
+}
+
+impl Row {
+    pub fn solved(day: u8, part: u8, answer: String, elapsed: Duration) -> Self {
+        Self { day, part, answer, elapsed: Some(elapsed) }
+    }
+
+    pub fn failed(day: u8, part: u8, reason: String) -> Self {
+        Self { day, part, answer: reason, elapsed: None }
+    }
+
+    /// Only failed rows go without a time.
+    pub fn is_failed(&self) -> bool {
+        self.elapsed.is_none()
+    }
+
+    fn cells(&self) -> Vec<String> {
+        vec![self.day.to_string(), self.part.to_string(), self.answer.clone(), format_elapsed(self.elapsed)]
+    }
+}
+
+pub fn format_elapsed(elapsed: Option<Duration>) -> String {
+    let Some(duration) = elapsed else {
+        return "-".to_string();
+    };
+    let millis = duration.as_secs_f64() * 1000.0;
+    if millis < 1000.0 {
+        format!("{millis:.2} ms")
+    } else {
+        format!("{:.2} s", millis / 1000.0)
+    }
+}
+
+pub fn render(rows: &[Row]) -> String {
+    let mut table = Table::new(&[("Day", Align::Right), ("Part", Align::Right), ("Answer", Align::Left), ("Time", Align::Right)]);
+    rows.iter().for_each(|row| table.push(row.cells()));
+    table.render()
+}
+
+pub fn print(rows: &[Row]) {
+    print!("{}", render(rows));
+}
+
+/// Time and peak allocation of parsing or solving a part, for the `profile` feature.
+pub struct PhaseRow {
+    day: u8,
+    phase: String,
+    measurement: Measurement,
+}
+
+impl PhaseRow {
+    pub fn new(day: u8, phase: &str, measurement: Measurement) -> Self {
+        Self { day, phase: phase.to_string(), measurement }
+    }
+
+    fn cells(&self) -> Vec<String> {
+        let peak = self.measurement.peak_bytes.map_or_else(|| "-".to_string(), |bytes| Bytes(bytes).to_string());
+        vec![self.day.to_string(), self.phase.clone(), format_elapsed(Some(self.measurement.elapsed)), peak]
+    }
+}
+
+pub fn render_phases(rows: &[PhaseRow]) -> String {
+    let mut table = Table::new(&[("Day", Align::Right), ("Phase", Align::Left), ("Time", Align::Right), ("Peak", Align::Right)]);
+    rows.iter().for_each(|row| table.push(row.cells()));
+    table.render()
+}
+
+pub fn print_phases(rows: &[PhaseRow]) {
+    print!("{}", render_phases(rows));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use common::profile::Measurement;
+    use crate::table::{render, render_phases, PhaseRow, Row};
+
+    #[test]
+    fn test_render() {
+        let rows = vec![
+            Row::solved(7, 1, "3749".to_string(), Duration::from_micros(1500)),
+            Row::failed(7, 2, "no input".to_string()),
+        ];
+        let expected = "\
+Day | Part | Answer   |    Time
+----+------+----------+--------
+  7 |    1 | 3749     | 1.50 ms
+  7 |    2 | no input |       -
+";
+        assert_eq!(render(&rows), expected);
+    }
+
+    #[test]
+    fn test_render_phases() {
+        let rows = vec![
+            PhaseRow::new(18, "parse", Measurement { elapsed: Duration::from_micros(250), peak_bytes: Some(40 << 10) }),
+            PhaseRow::new(18, "part 2", Measurement { elapsed: Duration::from_millis(1250), peak_bytes: None }),
+        ];
+        let expected = "\
+Day | Phase  |    Time |     Peak
+----+--------+---------+---------
+ 18 | parse  | 0.25 ms | 40.0 KiB
+ 18 | part 2 |  1.25 s |        -
+";
+        assert_eq!(render_phases(&rows), expected);
+    }
+}
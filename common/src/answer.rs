@@ -0,0 +1,99 @@
+use std::fmt;
+
+use serde::{ser::SerializeMap, Serialize, Serializer};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    NotFound,
+    /// The computation failed, e.g. it overflowed
+    Error(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Text(text) => write!(f, "{text}"),
+            Answer::NotFound => write!(f, "not found"),
+            Answer::Error(message) => write!(f, "error: {message}"),
+        }
+    }
+}
+
+/// Numbers stay numbers in JSON, a missing answer is `null` and an error is `{"error": message}`.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Number(number) => serializer.serialize_i128(*number),
+            Answer::Text(text) => serializer.serialize_str(text),
+            Answer::NotFound => serializer.serialize_none(),
+            Answer::Error(message) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("error", message)?;
+                map.end()
+            },
+        }
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($number:ty),*) => {
+        $(impl From<$number> for Answer {
+            fn from(number: $number) -> Self {
+                Answer::Number(number as i128)
+            }
+        })*
+    };
+}
+
+impl_from_number!(u32, u64, usize, i32, i64);
+
+impl From<i128> for Answer {
+    fn from(number: i128) -> Self {
+        Answer::Number(number)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(answer: Option<T>) -> Self {
+        answer.map_or(Answer::NotFound, Into::into)
+    }
+}
+
+impl<T: Into<Answer>, E: fmt::Display> From<Result<T, E>> for Answer {
+    fn from(answer: Result<T, E>) -> Self {
+        answer.map_or_else(|error| Answer::Error(error.to_string()), Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Answer;
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(Answer::from(42u32), Answer::Number(42));
+        assert_eq!(Answer::from(-3i64), Answer::Number(-3));
+        assert_eq!(Answer::from("6,1"), Answer::Text("6,1".to_string()));
+        assert_eq!(Answer::from(Some(7usize)), Answer::Number(7));
+        assert_eq!(Answer::from(None::<usize>), Answer::NotFound);
+        assert_eq!(Answer::from(Ok::<i128, String>(-7)), Answer::Number(-7));
+        let error = Answer::from(Err::<u32, &str>("sum overflows"));
+        assert_eq!(error, Answer::Error("sum overflows".to_string()));
+        assert_eq!(error.to_string(), "error: sum overflows");
+    }
+}
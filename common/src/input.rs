@@ -0,0 +1,93 @@
+use std::{fmt, fs::{self, File}, io::{self, BufRead, BufReader, Read}, path::PathBuf};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// `-` means stdin, anything else is a path. Without an argument we fall back to `input/<day>`,
+    /// which is where the inputs live when running from the workspace root.
+    pub fn from_arg(day: &str, arg: Option<&str>) -> Self {
+        match arg {
+            Some("-") => Self::Stdin,
+            Some(path) => Self::File(PathBuf::from(path)),
+            None => Self::File(default_path(day)),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        let result = match self {
+            InputSource::File(path) => fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            },
+        };
+        result.map_err(|error| InputError { source: self.clone(), error })
+    }
+
+    /// For the days that can read their input a line at a time instead of all at once.
+    pub fn open(&self) -> Result<Box<dyn BufRead>, InputError> {
+        match self {
+            InputSource::File(path) => match File::open(path) {
+                Ok(file) => Ok(Box::new(BufReader::new(file))),
+                Err(error) => Err(InputError { source: self.clone(), error }),
+            },
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+pub fn default_path(day: &str) -> PathBuf {
+    PathBuf::from("input").join(day)
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub source: InputSource,
+    pub error: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.source {
+            InputSource::File(_) if self.error.kind() == io::ErrorKind::NotFound =>
+                write!(f, "input file {} not found (pass a path, or `-` to read from stdin)", self.source),
+            _ => write!(f, "cannot read input from {}: {}", self.source, self.error),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use crate::input::InputSource;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(InputSource::from_arg("day-01", None), InputSource::File(PathBuf::from("input/day-01")));
+        assert_eq!(InputSource::from_arg("day-01", Some("-")), InputSource::Stdin);
+        assert_eq!(InputSource::from_arg("day-01", Some("other")), InputSource::File(PathBuf::from("other")));
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let error = InputSource::from_arg("day-01", Some("does/not/exist")).read().unwrap_err();
+        assert_eq!(error.to_string(), "input file does/not/exist not found (pass a path, or `-` to read from stdin)");
+        let error = InputSource::from_arg("day-01", Some("does/not/exist")).open().err().unwrap();
+        assert_eq!(error.to_string(), "input file does/not/exist not found (pass a path, or `-` to read from stdin)");
+    }
+}
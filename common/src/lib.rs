@@ -0,0 +1,10 @@
+pub mod answer;
+pub mod input;
+pub mod parse;
+pub mod profile;
+pub mod runner;
+pub mod solution;
+
+pub use answer::Answer;
+pub use parse::ParseError;
+pub use solution::Solution;
@@ -0,0 +1,244 @@
+use std::{fmt, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub location: Option<Location>,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self { location: None, message: message.into() }
+    }
+
+    /// Lines and columns both start at 1, like in an editor.
+    pub fn at(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self { location: Some(Location { line, column }), message: message.into() }
+    }
+
+    /// Points an error raised without context (e.g. by a `TryFrom<char>`) at where it happened.
+    pub fn located(self, line: usize, column: usize) -> Self {
+        match self.location {
+            Some(_) => self,
+            None => Self::at(line, column, self.message),
+        }
+    }
+
+    /// Error for an input that stops before we're done with it.
+    pub fn end_of_input(input: &str, message: impl Into<String>) -> Self {
+        let message = format!("unexpected end of input, {}", message.into());
+        Self::at(input.lines().count() + 1, 1, message)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.location {
+            Some(Location { line, column }) => write!(f, "line {line}, col {column}: {}", self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Walks through a single line of input, keeping track of where we are for error messages.
+pub struct Cursor<'a> {
+    line: usize,
+    text: &'a str,
+    offset: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(line: usize, text: &'a str) -> Self {
+        Self { line, text, offset: 0 }
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.text[..self.offset].chars().count() + 1
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.offset..]
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.offset == self.text.len()
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError::at(self.line, self.column(), message)
+    }
+
+    pub fn next_char(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.offset += c.len_utf8();
+        Some(c)
+    }
+
+    /// Consumes `literal` if the line continues with it.
+    pub fn eat(&mut self, literal: &str) -> bool {
+        if self.rest().starts_with(literal) {
+            self.offset += literal.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consumes any whitespace, returning how many characters of it there were.
+    pub fn skip_whitespace(&mut self) -> usize {
+        let rest = self.rest();
+        let length = rest.len() - rest.trim_start().len();
+        self.offset += length;
+        rest[..length].chars().count()
+    }
+
+    pub fn expect(&mut self, literal: &str) -> Result<(), ParseError> {
+        if self.eat(literal) {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected `{literal}`")))
+        }
+    }
+
+    /// Reads a decimal number, with an optional leading `-` for the types that accept one.
+    pub fn number<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let rest = self.rest();
+        let sign = usize::from(rest.starts_with('-'));
+        let digits = rest[sign..].chars().take_while(|c| c.is_ascii_digit()).count();
+        if digits == 0 {
+            return Err(ParseError::at(self.line, self.column() + sign, "expected digit"));
+        }
+        let number = &rest[..sign + digits];
+        match number.parse::<T>() {
+            Ok(value) => {
+                self.offset += number.len();
+                Ok(value)
+            },
+            Err(_) if sign == 1 => Err(self.error("expected digit")),
+            Err(_) => Err(self.error(format!("number `{number}` is out of range"))),
+        }
+    }
+
+    /// Takes everything up to `delimiter`, or up to the end of the line.
+    pub fn take_until(&mut self, delimiter: &str) -> &'a str {
+        let rest = self.rest();
+        let length = rest.find(delimiter).unwrap_or(rest.len());
+        self.offset += length;
+        &rest[..length]
+    }
+
+    pub fn finish(&self) -> Result<(), ParseError> {
+        match self.peek() {
+            None => Ok(()),
+            Some(c) => Err(self.error(format!("unexpected `{c}`"))),
+        }
+    }
+}
+
+/// A cursor for every line of `input`, numbered from 1.
+pub fn lines(input: &str) -> impl Iterator<Item = Cursor<'_>> {
+    input.lines().enumerate().map(|(index, line)| Cursor::new(index + 1, line))
+}
+
+/// Parses each line with `parse_line`, making sure nothing is left over at the end of the line.
+pub fn parse_lines<T>(input: &str, mut parse_line: impl FnMut(&mut Cursor) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+    lines(input).map(|mut cursor| {
+        let value = parse_line(&mut cursor)?;
+        cursor.finish()?;
+        Ok(value)
+    }).collect()
+}
+
+/// Parses a rectangular map, one cell per character.
+pub fn parse_grid<T>(input: &str, mut parse_cell: impl FnMut(char) -> Result<T, ParseError>) -> Result<Vec<Vec<T>>, ParseError> {
+    let mut width = None;
+    let grid = lines(input).map(|mut cursor| {
+        let mut row = vec![];
+        while let Some(c) = cursor.peek() {
+            row.push(parse_cell(c).map_err(|error| error.located(cursor.line(), cursor.column()))?);
+            cursor.next_char();
+        }
+        match width {
+            None if row.is_empty() => return Err(cursor.error("expected a map row")),
+            None => width = Some(row.len()),
+            Some(width) if width != row.len() => {
+                return Err(cursor.error(format!("expected {width} cells on this row, found {}", row.len())));
+            },
+            Some(_) => {},
+        }
+        Ok(row)
+    }).collect::<Result<Vec<Vec<T>>, ParseError>>()?;
+    if grid.is_empty() {
+        return Err(ParseError::end_of_input(input, "expected a map"));
+    }
+    Ok(grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{parse_grid, parse_lines, Cursor, ParseError};
+
+    #[test]
+    fn test_cursor() {
+        let mut cursor = Cursor::new(3, "p=-12,7 v=3");
+        cursor.expect("p=").unwrap();
+        assert_eq!(cursor.number::<i32>(), Ok(-12));
+        cursor.expect(",").unwrap();
+        assert_eq!(cursor.number::<u8>(), Ok(7));
+        assert_eq!(cursor.expect(" w=").unwrap_err().to_string(), "line 3, col 8: expected ` w=`");
+        cursor.expect(" v=").unwrap();
+        assert_eq!(cursor.take_until(","), "3");
+        assert!(cursor.finish().is_ok());
+    }
+
+    #[test]
+    fn test_skip_whitespace() {
+        let mut cursor = Cursor::new(1, " \t 12  ");
+        assert_eq!(cursor.skip_whitespace(), 3);
+        assert_eq!(cursor.skip_whitespace(), 0);
+        assert_eq!(cursor.number::<u32>(), Ok(12));
+        assert_eq!(cursor.skip_whitespace(), 2);
+        assert!(cursor.is_at_end());
+    }
+
+    #[test]
+    fn test_number_errors() {
+        assert_eq!(Cursor::new(1, "ab").number::<u32>().unwrap_err().to_string(), "line 1, col 1: expected digit");
+        assert_eq!(Cursor::new(1, "-3").number::<u32>().unwrap_err().to_string(), "line 1, col 1: expected digit");
+        assert_eq!(Cursor::new(1, "-x").number::<i32>().unwrap_err().to_string(), "line 1, col 2: expected digit");
+        assert_eq!(Cursor::new(1, "300").number::<u8>().unwrap_err().to_string(), "line 1, col 1: number `300` is out of range");
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let parsed = parse_lines("1\n2\n3", |cursor| cursor.number::<u32>());
+        assert_eq!(parsed, Ok(vec![1, 2, 3]));
+        let error = parse_lines("1\n2\n3 4", |cursor| cursor.number::<u32>()).unwrap_err();
+        assert_eq!(error.to_string(), "line 3, col 2: unexpected ` `");
+    }
+
+    #[test]
+    fn test_parse_grid() {
+        let digit = |c: char| c.to_digit(10).ok_or_else(|| ParseError::new("expected digit"));
+        assert_eq!(parse_grid("12\n34", digit), Ok(vec![vec![1, 2], vec![3, 4]]));
+        assert_eq!(parse_grid("12\n3x", digit).unwrap_err().to_string(), "line 2, col 2: expected digit");
+        assert_eq!(parse_grid("12\n345", digit).unwrap_err().to_string(), "line 2, col 4: expected 2 cells on this row, found 3");
+        assert_eq!(parse_grid("", digit).unwrap_err().to_string(), "line 1, col 1: unexpected end of input, expected a map");
+    }
+}
@@ -0,0 +1,158 @@
+use std::{fmt, time::{Duration, Instant}};
+
+/// Whether peak allocations are tracked, i.e. whether the `profile` feature is on.
+pub const ENABLED: bool = cfg!(feature = "profile");
+
+#[cfg(feature = "profile")]
+mod allocator {
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    static CURRENT: AtomicUsize = AtomicUsize::new(0);
+    static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+    /// The system allocator, counting how many bytes are in use.
+    struct Counting;
+
+    fn grow(size: usize) {
+        let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+    }
+
+    fn shrink(size: usize) {
+        CURRENT.fetch_sub(size, Ordering::Relaxed);
+    }
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let pointer = unsafe { System.alloc(layout) };
+            if !pointer.is_null() {
+                grow(layout.size());
+            }
+            pointer
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let pointer = unsafe { System.alloc_zeroed(layout) };
+            if !pointer.is_null() {
+                grow(layout.size());
+            }
+            pointer
+        }
+
+        unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+            unsafe { System.dealloc(pointer, layout) };
+            shrink(layout.size());
+        }
+
+        unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_pointer = unsafe { System.realloc(pointer, layout, new_size) };
+            if !new_pointer.is_null() {
+                grow(new_size);
+                shrink(layout.size());
+            }
+            new_pointer
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    /// Forgets the previous peak, returning how many bytes are in use now.
+    pub fn reset_peak() -> usize {
+        let current = CURRENT.load(Ordering::Relaxed);
+        PEAK.store(current, Ordering::Relaxed);
+        current
+    }
+
+    pub fn peak() -> usize {
+        PEAK.load(Ordering::Relaxed)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Measurement {
+    pub elapsed: Duration,
+    /// Most bytes allocated at once on top of what was in use before, without the `profile` feature
+    /// it isn't tracked. Allocations of other threads count too.
+    pub peak_bytes: Option<usize>,
+}
+
+/// Runs `f`, timing it and, with the `profile` feature, tracking its peak allocation.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Measurement) {
+    #[cfg(feature = "profile")]
+    let before = allocator::reset_peak();
+    let start = Instant::now();
+    let value = f();
+    let elapsed = start.elapsed();
+    #[cfg(feature = "profile")]
+    let peak_bytes = Some(allocator::peak().saturating_sub(before));
+    #[cfg(not(feature = "profile"))]
+    let peak_bytes = None;
+    (value, Measurement { elapsed, peak_bytes })
+}
+
+/// One line per phase, e.g. `part 1  12.345 ms  peak 1.5 MiB`.
+pub fn summary(phases: &[(&str, Measurement)]) -> String {
+    let width = phases.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+    phases.iter().map(|(name, measurement)| {
+        let millis = measurement.elapsed.as_secs_f64() * 1000.0;
+        match measurement.peak_bytes {
+            Some(peak_bytes) => format!("{name:<width$}  {millis:>10.3} ms  peak {}\n", Bytes(peak_bytes)),
+            None => format!("{name:<width$}  {millis:>10.3} ms\n"),
+        }
+    }).collect()
+}
+
+/// Bytes in binary units, e.g. `1.5 MiB`.
+pub struct Bytes(pub usize);
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut size = self.0 as f64;
+        for unit in ["B", "KiB", "MiB"] {
+            if size < 1024.0 {
+                return match unit {
+                    "B" => write!(f, "{} B", self.0),
+                    _ => write!(f, "{size:.1} {unit}"),
+                };
+            }
+            size /= 1024.0;
+        }
+        write!(f, "{size:.1} GiB")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{hint::black_box, time::Duration};
+    use crate::profile::{measure, summary, Bytes, Measurement, ENABLED};
+
+    #[test]
+    fn test_bytes() {
+        assert_eq!(Bytes(0).to_string(), "0 B");
+        assert_eq!(Bytes(1023).to_string(), "1023 B");
+        assert_eq!(Bytes(1536).to_string(), "1.5 KiB");
+        assert_eq!(Bytes(3 << 20).to_string(), "3.0 MiB");
+        assert_eq!(Bytes(5 << 30).to_string(), "5.0 GiB");
+    }
+
+    #[test]
+    fn test_measure() {
+        let (length, measurement) = measure(|| black_box(vec![0u8; 1 << 20]).len());
+        assert_eq!(length, 1 << 20);
+        match ENABLED {
+            true => assert!(measurement.peak_bytes.unwrap() >= 1 << 20),
+            false => assert_eq!(measurement.peak_bytes, None),
+        }
+    }
+
+    #[test]
+    fn test_summary() {
+        let parse = Measurement { elapsed: Duration::from_micros(120), peak_bytes: Some(2048) };
+        let part1 = Measurement { elapsed: Duration::from_millis(1500), peak_bytes: None };
+        assert_eq!(summary(&[("parse", parse), ("part 1", part1)]), "parse        0.120 ms  peak 2.0 KiB\npart 1    1500.000 ms\n");
+    }
+}
@@ -0,0 +1,200 @@
+use std::{io::BufRead, process, time::Duration};
+
+use serde::Serialize;
+
+use crate::{input::InputSource, profile, solution::parse_or_exit, Answer, ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// `Label: answer`, one line per part
+    Text,
+    /// One JSON object per line and part, for tools
+    Json,
+}
+
+/// Command line of the day binaries: `day-NN [INPUT|-] [--format text|json]`.
+#[derive(Debug, PartialEq)]
+pub struct Args {
+    pub input: Option<String>,
+    pub format: Format,
+}
+
+impl Args {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut args: Vec<String> = args.into_iter().collect();
+        let format = match take_option(&mut args, "--format")?.as_deref() {
+            None | Some("text") => Format::Text,
+            Some("json") => Format::Json,
+            Some(value) => return Err(format!("unknown format `{value}`, expected `text` or `json`")),
+        };
+        let mut input = None;
+        for arg in args {
+            match arg {
+                _ if arg.starts_with("--") => return Err(format!("unknown option `{arg}`")),
+                _ if input.is_some() => return Err(format!("unexpected argument `{arg}`")),
+                _ => input = Some(arg),
+            }
+        }
+        Ok(Self { input, format })
+    }
+}
+
+/// Removes the flag `--name` from `args`, telling whether it was there.
+pub fn take_flag(args: &mut Vec<String>, name: &str) -> bool {
+    let length = args.len();
+    args.retain(|arg| arg != name);
+    args.len() != length
+}
+
+/// Removes `--name value` or `--name=value` from `args`, for the days that take options of their own.
+pub fn take_option(args: &mut Vec<String>, name: &str) -> Result<Option<String>, String> {
+    let Some(index) = args.iter().position(|arg| arg == name || arg.starts_with(&format!("{name}="))) else {
+        return Ok(None);
+    };
+    let arg = args.remove(index);
+    match arg.strip_prefix(&format!("{name}=")) {
+        Some(value) => Ok(Some(value.to_string())),
+        None if index < args.len() => Ok(Some(args.remove(index))),
+        None => Err(format!("{name} expects a value")),
+    }
+}
+
+#[derive(Serialize)]
+struct JsonLine<'a> {
+    day: u8,
+    part: u8,
+    answer: &'a Answer,
+    elapsed_ms: f64,
+}
+
+pub fn json_line(day: u8, part: u8, answer: &Answer, elapsed: Duration) -> String {
+    let line = JsonLine { day, part, answer, elapsed_ms: elapsed.as_secs_f64() * 1000.0 };
+    serde_json::to_string(&line).unwrap()
+}
+
+/// What `run` computed, for the days that do more with it than printing.
+pub struct Run<S> {
+    pub solution: S,
+    pub format: Format,
+    pub answers: [Answer; 2],
+}
+
+/// The whole `main` of a day: reads the input named on the command line, solves both parts and
+/// prints the answers under `labels`, or exits with a message when something's wrong.
+pub fn run<S: Solution>(day: u8, labels: [&str; 2]) -> Run<S> {
+    run_with_args(day, labels, parse_args_or_exit(day, std::env::args().skip(1)))
+}
+
+pub fn parse_args_or_exit(day: u8, args: impl IntoIterator<Item = String>) -> Args {
+    match Args::parse(args) {
+        Ok(args) => args,
+        Err(error) => usage_error(day, &error, ""),
+    }
+}
+
+/// Exits after a usage message, `extra_usage` being the options that the day adds to the usual ones.
+pub fn usage_error(day: u8, error: &str, extra_usage: &str) -> ! {
+    eprintln!("error: {error}");
+    eprintln!("usage: day-{day:02} [INPUT|-] [--format text|json]{extra_usage}");
+    process::exit(2);
+}
+
+/// Like `run`, for days that took their own options out of the command line first.
+pub fn run_with_args<S: Solution>(day: u8, labels: [&str; 2], args: Args) -> Run<S> {
+    run_with_parser(day, labels, args, S::parse)
+}
+
+/// Like `run_with_args`, for days whose options change how the input is parsed or solved.
+pub fn run_with_parser<S: Solution>(day: u8, labels: [&str; 2], args: Args, parse: impl FnOnce(&str) -> Result<S, ParseError>) -> Run<S> {
+    let input = match InputSource::from_arg(&format!("day-{day:02}"), args.input.as_deref()).read() {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("error: {error}");
+            process::exit(1);
+        },
+    };
+    solve(day, labels, args.format, || parse_or_exit(&input, parse))
+}
+
+/// Like `run_with_parser`, for days that parse their input a line at a time instead of reading it all first.
+pub fn run_with_reader<S: Solution>(day: u8, labels: [&str; 2], args: Args, read: impl FnOnce(Box<dyn BufRead>) -> Result<S, ParseError>) -> Run<S> {
+    let reader = match InputSource::from_arg(&format!("day-{day:02}"), args.input.as_deref()).open() {
+        Ok(reader) => reader,
+        Err(error) => {
+            eprintln!("error: {error}");
+            process::exit(1);
+        },
+    };
+    solve(day, labels, args.format, || match read(reader) {
+        Ok(solution) => solution,
+        Err(error) => {
+            eprintln!("error: invalid input: {error}");
+            process::exit(1);
+        },
+    })
+}
+
+// parses with `parse`, then prints the answers of both parts
+fn solve<S: Solution>(day: u8, labels: [&str; 2], format: Format, parse: impl FnOnce() -> S) -> Run<S> {
+    let (solution, parse) = profile::measure(parse);
+    let mut measurements = vec![("parse", parse)];
+    let answers = [1, 2].map(|part| {
+        let (answer, measurement) = profile::measure(|| solution.part(part));
+        match format {
+            Format::Text => println!("{}: {answer}", labels[part as usize - 1]),
+            Format::Json => println!("{}", json_line(day, part, &answer, measurement.elapsed)),
+        }
+        measurements.push((["part 1", "part 2"][part as usize - 1], measurement));
+        answer
+    });
+    // on stderr, so that it doesn't get in the way of the answers
+    if profile::ENABLED {
+        eprint!("{}", profile::summary(&measurements));
+    }
+    Run { solution, format, answers }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use crate::{runner::{json_line, take_flag, take_option, Args, Format}, Answer};
+
+    fn parse(args: &[&str]) -> Result<Args, String> {
+        Args::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_args() {
+        assert_eq!(parse(&[]), Ok(Args { input: None, format: Format::Text }));
+        assert_eq!(parse(&["-", "--format", "json"]), Ok(Args { input: Some("-".to_string()), format: Format::Json }));
+        assert_eq!(parse(&["--format=text", "input/day-01"]), Ok(Args { input: Some("input/day-01".to_string()), format: Format::Text }));
+        assert_eq!(parse(&["--format", "xml"]), Err("unknown format `xml`, expected `text` or `json`".to_string()));
+        assert_eq!(parse(&["--format"]), Err("--format expects a value".to_string()));
+        assert_eq!(parse(&["--verbose"]), Err("unknown option `--verbose`".to_string()));
+        assert_eq!(parse(&["a", "b"]), Err("unexpected argument `b`".to_string()));
+    }
+
+    #[test]
+    fn test_take_option() {
+        let mut args = vec!["input".to_string(), "--metric".to_string(), "squared".to_string(), "--format=json".to_string()];
+        assert_eq!(take_option(&mut args, "--metric"), Ok(Some("squared".to_string())));
+        assert_eq!(take_option(&mut args, "--format"), Ok(Some("json".to_string())));
+        assert_eq!(take_option(&mut args, "--metric"), Ok(None));
+        assert_eq!(args, vec!["input"]);
+        assert_eq!(take_option(&mut vec!["--metric".to_string()], "--metric"), Err("--metric expects a value".to_string()));
+        let mut args = vec!["--allow-equal".to_string(), "input".to_string()];
+        assert!(take_flag(&mut args, "--allow-equal"));
+        assert!(!take_flag(&mut args, "--allow-equal"));
+        assert_eq!(args, vec!["input"]);
+    }
+
+    #[test]
+    fn test_json_line() {
+        let elapsed = Duration::from_micros(1500);
+        assert_eq!(json_line(20, 1, &Answer::Number(1485), elapsed), r#"{"day":20,"part":1,"answer":1485,"elapsed_ms":1.5}"#);
+        assert_eq!(json_line(17, 1, &Answer::from("4,6,3"), elapsed), r#"{"day":17,"part":1,"answer":"4,6,3","elapsed_ms":1.5}"#);
+        assert_eq!(json_line(14, 2, &Answer::NotFound, elapsed), r#"{"day":14,"part":2,"answer":null,"elapsed_ms":1.5}"#);
+        let error = Answer::Error("overflow".to_string());
+        assert_eq!(json_line(1, 2, &error, elapsed), r#"{"day":1,"part":2,"answer":{"error":"overflow"},"elapsed_ms":1.5}"#);
+    }
+}
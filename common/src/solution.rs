@@ -0,0 +1,35 @@
+use std::process;
+
+use crate::{Answer, ParseError};
+
+/// What every day provides: parse the puzzle input once, then answer both parts from it.
+pub trait Solution {
+    fn parse(input: &str) -> Result<Self, ParseError> where Self: Sized;
+
+    fn part1(&self) -> Answer;
+
+    fn part2(&self) -> Answer;
+
+    fn part(&self, part: u8) -> Answer {
+        match part {
+            1 => self.part1(),
+            2 => self.part2(),
+            _ => panic!("there is no part {part}"),
+        }
+    }
+}
+
+/// Boxed parser, so that callers can keep every day in a single table.
+pub fn parse_boxed<S: Solution + 'static>(input: &str) -> Result<Box<dyn Solution>, ParseError> {
+    Ok(Box::new(S::parse(input)?))
+}
+
+pub fn parse_or_exit<S>(input: &str, parse: impl FnOnce(&str) -> Result<S, ParseError>) -> S {
+    match parse(input) {
+        Ok(solution) => solution,
+        Err(error) => {
+            eprintln!("error: invalid input: {error}");
+            process::exit(1);
+        },
+    }
+}
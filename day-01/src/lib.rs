@@ -0,0 +1,210 @@
+pub mod metrics;
+
+use std::{collections::HashMap, fmt, io::BufRead, sync::OnceLock};
+use common::{parse::Cursor, Answer, ParseError, Solution};
+use metrics::{Metric, PairDiff, Value};
+
+pub struct LocationLists {
+    list1: Vec<i64>,
+    list2: Vec<i64>,
+    // sorted copies, made the first time a part or metric needs them
+    sorted: OnceLock<(Vec<i64>, Vec<i64>)>,
+}
+
+impl LocationLists {
+    pub fn new(list1: Vec<i64>, list2: Vec<i64>) -> Self {
+        Self { list1, list2, sorted: OnceLock::new() }
+    }
+
+    pub fn read(reader: impl BufRead) -> Result<Self, ParseError> {
+        let (list1, list2) = read_lists(reader)?;
+        Ok(Self::new(list1, list2))
+    }
+
+    /// The lists in input order, a line being one item of each.
+    pub fn lists(&self) -> (&[i64], &[i64]) {
+        (&self.list1, &self.list2)
+    }
+
+    pub fn sorted(&self) -> (&[i64], &[i64]) {
+        let (list1, list2) = self.sorted.get_or_init(|| {
+            let (mut list1, mut list2) = (self.list1.clone(), self.list2.clone());
+            list1.sort_unstable();
+            list2.sort_unstable();
+            (list1, list2)
+        });
+        (list1, list2)
+    }
+
+    pub fn compute(&self, metric: Metric) -> Result<Value, Overflow> {
+        metric.compute(self)
+    }
+
+    pub fn pair_diffs(&self) -> Vec<PairDiff> {
+        let (list1, list2) = self.sorted();
+        metrics::pair_diffs(list1, list2)
+    }
+}
+
+impl TryFrom<&str> for LocationLists {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Self::read(input.as_bytes())
+    }
+}
+
+// two numbers, separated by any whitespace
+fn parse_pair(cursor: &mut Cursor) -> Result<(i64, i64), ParseError> {
+    cursor.skip_whitespace();
+    let item1 = cursor.number::<i64>()?;
+    if cursor.skip_whitespace() == 0 {
+        return Err(cursor.error("expected whitespace between the two numbers"));
+    }
+    let item2 = cursor.number::<i64>()?;
+    cursor.skip_whitespace();
+    cursor.finish()?;
+    Ok((item1, item2))
+}
+
+/// Reads the lists one line at a time, so that the whole input never has to be in memory.
+/// Blank lines are skipped.
+pub fn read_lists(mut reader: impl BufRead) -> Result<(Vec<i64>, Vec<i64>), ParseError> {
+    let (mut list1, mut list2) = (vec![], vec![]);
+    let mut line = String::new();
+    for number in 1.. {
+        line.clear();
+        match reader.read_line(&mut line) {
+            Ok(0) => break,
+            Ok(_) => {},
+            Err(error) => return Err(ParseError::at(number, 1, format!("cannot read line: {error}"))),
+        }
+        let text = line.trim_end_matches(['\n', '\r']);
+        if text.trim().is_empty() {
+            continue;
+        }
+        let (item1, item2) = parse_pair(&mut Cursor::new(number, text))?;
+        list1.push(item1);
+        list2.push(item2);
+    }
+    Ok((list1, list2))
+}
+
+pub fn create_lists(input: &str) -> Result<(Vec<i64>, Vec<i64>), ParseError> {
+    read_lists(input.as_bytes())
+}
+
+/// A sum that doesn't fit in an `i128`, which only huge lists of huge numbers can reach.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow {
+    pub metric: &'static str,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the {} overflows 128 bits", self.metric)
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// Adds up `terms`, failing as soon as a term or the sum overflows.
+pub(crate) fn checked_sum(metric: &'static str, mut terms: impl Iterator<Item = Option<i128>>) -> Result<i128, Overflow> {
+    terms.try_fold(0i128, |sum, term| term.and_then(|term| sum.checked_add(term))).ok_or(Overflow { metric })
+}
+
+pub fn compute_distance(mut list1: Vec<i64>, mut list2: Vec<i64>) -> Result<i128, Overflow> {
+    list1.sort_unstable();
+    list2.sort_unstable();
+    sorted_distance(&list1, &list2)
+}
+
+/// `compute_distance` of lists that are already sorted.
+pub fn sorted_distance(list1: &[i64], list2: &[i64]) -> Result<i128, Overflow> {
+    checked_sum("distance", list1.iter().zip(list2.iter()).map(|(item1, item2)| Some(item1.abs_diff(*item2) as i128)))
+}
+
+pub fn compute_similarity(list1: &[i64], list2: &[i64]) -> Result<i128, Overflow> {
+    let mut list2_hashmap: HashMap<i64, i128> = HashMap::new();
+    list2.iter().for_each(|item| {
+        list2_hashmap.entry(*item).and_modify(|count| *count += 1).or_insert(1);
+    });
+    checked_sum("similarity", list1.iter().map(|item| (*item as i128).checked_mul(*list2_hashmap.get(item).unwrap_or(&0))))
+}
+
+impl Solution for LocationLists {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        let (list1, list2) = self.sorted();
+        sorted_distance(list1, list2).into()
+    }
+
+    fn part2(&self) -> Answer {
+        compute_similarity(&self.list1, &self.list2).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+    use crate::{compute_distance, compute_similarity, create_lists, read_lists};
+    use common::{Answer, Solution};
+    use crate::LocationLists;
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../input/day-01-test");
+        let (list1, list2) = create_lists(input).unwrap();
+        let distance = compute_distance(list1, list2);
+        assert_eq!(distance, Ok(11));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../input/day-01-test");
+        let (list1, list2) = create_lists(input).unwrap();
+        let distance = compute_similarity(&list1, &list2);
+        assert_eq!(distance, Ok(31));
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let error = create_lists("3   4\n4   x\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, col 5: expected digit");
+        let error = create_lists("3   4\n\n34\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 3, col 3: expected whitespace between the two numbers");
+        let error = create_lists("3 4 5\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, col 5: unexpected `5`");
+        let error = read_lists(&[b'1', b' ', 0xff, b'\n'][..]).unwrap_err();
+        assert!(error.to_string().starts_with("line 1, col 1: cannot read line:"), "{error}");
+    }
+
+    #[test]
+    fn test_any_whitespace() {
+        let lists = create_lists("3\t4\n4  3 \r\n 2 5\n\n").unwrap();
+        assert_eq!(lists, (vec![3, 4, 2], vec![4, 3, 5]));
+    }
+
+    #[test]
+    fn test_read_lists() {
+        let input = include_str!("../../input/day-01-test");
+        // a tiny buffer, so that lines span several reads
+        let lists = read_lists(BufReader::with_capacity(4, input.as_bytes())).unwrap();
+        assert_eq!(lists, create_lists(input).unwrap());
+        assert_eq!(compute_distance(lists.0, lists.1), Ok(11));
+    }
+
+    #[test]
+    fn test_signed_values() {
+        let (list1, list2) = create_lists("-3   -3\n2   -3\n").unwrap();
+        assert_eq!(compute_distance(list1.clone(), list2.clone()), Ok(5));
+        assert_eq!(compute_similarity(&list1, &list2), Ok(-6));
+        let lists = LocationLists::try_from("-9223372036854775808 9223372036854775807").unwrap();
+        assert_eq!(lists.part1(), Answer::Number(u64::MAX as i128));
+        let error = create_lists("1 9223372036854775808").err().unwrap();
+        assert_eq!(error.to_string(), "line 1, col 3: number `9223372036854775808` is out of range");
+    }
+}
\ No newline at end of file
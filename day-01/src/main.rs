@@ -1,58 +1,77 @@
-use std::collections::HashMap;
+use std::{env, io::{self, BufWriter, Write}, process};
 
-fn main() {
-    let input = include_str!("../../input/day-01");
-    let (list1, list2) = create_lists(input);
-    let distance = compute_distance(list1.clone(), list2.clone());
-    println!("Distance: {distance}");
+use common::{
+    input::InputSource,
+    runner::{parse_args_or_exit, run_with_reader, take_option, usage_error, Format},
+};
+use day_01::{metrics::Metric, LocationLists};
+use serde_json::json;
 
-    let similarity = compute_similarity(list1, list2);
-    println!("Similarity: {similarity}");
-}
+const METRIC_USAGE: &str = " [--metric NAME|pairs]";
 
-fn create_lists(input: &str) -> (Vec<u32>, Vec<u32>) {
-    let mut list1 = vec![];
-    let mut list2 = vec![];
-    input.lines().for_each(|line| {
-        let split: Vec<&str> = line.split("   ").collect();
-        list1.push(split[0].parse::<u32>().unwrap());
-        list2.push(split[1].parse::<u32>().unwrap());
-    });
-    (list1, list2)
+/// What to print instead of the two parts, if anything.
+enum Report {
+    Metric(Metric),
+    /// Every pair of the sorted lists, biggest difference first
+    Pairs,
 }
 
-fn compute_distance(mut list1: Vec<u32>, mut list2: Vec<u32>) -> u32 {
-    list1.sort();
-    list2.sort();
-    list1.iter().zip(list2.iter()).map(|(item1, item2)| if item1 > item2 {item1 - item2} else {item2 - item1}).sum()
-}
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let report = match take_option(&mut args, "--metric") {
+        Ok(None) => None,
+        Ok(Some(name)) if name == "pairs" => Some(Report::Pairs),
+        Ok(Some(name)) => Some(Report::Metric(name.parse().unwrap_or_else(|error: String| usage_error(1, &error, METRIC_USAGE)))),
+        Err(error) => usage_error(1, &error, METRIC_USAGE),
+    };
+    let args = parse_args_or_exit(1, args);
+    // the lists can be huge, read them without keeping the whole input around
+    let Some(report) = report else {
+        run_with_reader(1, ["Distance", "Similarity"], args, LocationLists::read);
+        return;
+    };
 
-fn compute_similarity(list1: Vec<u32>, list2: Vec<u32>) -> u32 {
-    let mut list2_hashmap: HashMap<u32, u32> = HashMap::new();
-    list2.iter().for_each(|item| {
-        list2_hashmap.entry(*item).and_modify(|count| *count += 1).or_insert(1);
-    });
-    list1.iter().map(|item| item * list2_hashmap.get(item).unwrap_or(&0)).sum()
+    let source = InputSource::from_arg("day-01", args.input.as_deref());
+    let lists = match source.open().map_err(|error| error.to_string())
+        .and_then(|reader| LocationLists::read(reader).map_err(|error| format!("invalid input: {error}"))) {
+        Ok(lists) => lists,
+        Err(error) => {
+            eprintln!("error: {error}");
+            process::exit(1);
+        },
+    };
+    match (report, args.format) {
+        (Report::Metric(metric), format) => {
+            let value = match lists.compute(metric) {
+                Ok(value) => value,
+                Err(error) => {
+                    eprintln!("error: {error}");
+                    process::exit(1);
+                },
+            };
+            match format {
+                Format::Text => println!("{}: {value}", metric.name()),
+                Format::Json => println!("{}", json!({ "day": 1, "metric": metric.name(), "value": value })),
+            }
+        },
+        (Report::Pairs, format) => print_pairs(&lists, format),
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::{compute_distance, compute_similarity, create_lists};
-
-    #[test]
-    fn test_part1() {
-        let input = include_str!("../../input/day-01-test");
-        let (list1, list2) = create_lists(input);
-        let distance = compute_distance(list1, list2);
-        assert_eq!(distance, 11);
+// there can be millions of pairs, stop quietly when the reader has seen enough (e.g. `| head`)
+fn print_pairs(lists: &LocationLists, format: Format) {
+    let mut output = BufWriter::new(io::stdout().lock());
+    if format == Format::Text {
+        let _ = writeln!(output, "rank\tleft\tright\tdiff");
     }
-
-    #[test]
-    fn test_part2() {
-        let input = include_str!("../../input/day-01-test");
-        let (list1, list2) = create_lists(input);
-        let distance = compute_similarity(list1, list2);
-        assert_eq!(distance, 31);
+    for pair in lists.pair_diffs() {
+        let result = match format {
+            Format::Text => writeln!(output, "{}\t{}\t{}\t{}", pair.rank, pair.item1, pair.item2, pair.diff),
+            Format::Json => writeln!(output, "{}", json!(pair)),
+        };
+        if result.is_err() {
+            return;
+        }
     }
-
-}
\ No newline at end of file
+    let _ = output.flush();
+}
@@ -0,0 +1,220 @@
+use std::{collections::HashMap, fmt, str::FromStr};
+
+use serde::Serialize;
+
+use crate::{checked_sum, compute_similarity, sorted_distance, LocationLists, Overflow};
+
+/// Ways to compare the two lists, all symmetric except `Similarity`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    /// Sum of the differences between the sorted lists, part 1
+    Distance,
+    /// Items of the left list weighted by how often they appear in the right one, part 2
+    Similarity,
+    /// Sum of the squared differences between the sorted lists
+    SquaredDistance,
+    /// Jaccard index of the lists as multisets: shared items over all items, from 0 to 1
+    Overlap,
+    /// Pairs of lines whose two items are ordered differently in each list
+    KendallTau,
+}
+
+impl Metric {
+    pub const ALL: [Metric; 5] = [Metric::Distance, Metric::Similarity, Metric::SquaredDistance, Metric::Overlap, Metric::KendallTau];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Metric::Distance => "distance",
+            Metric::Similarity => "similarity",
+            Metric::SquaredDistance => "squared",
+            Metric::Overlap => "overlap",
+            Metric::KendallTau => "kendall-tau",
+        }
+    }
+
+    pub fn compute(&self, lists: &LocationLists) -> Result<Value, Overflow> {
+        let ((list1, list2), (sorted1, sorted2)) = (lists.lists(), lists.sorted());
+        Ok(match self {
+            Metric::Distance => Value::Number(sorted_distance(sorted1, sorted2)?),
+            Metric::Similarity => Value::Number(compute_similarity(list1, list2)?),
+            Metric::SquaredDistance => Value::Number(squared_distance(sorted1, sorted2)?),
+            Metric::Overlap => Value::Ratio(overlap(list1, list2)),
+            Metric::KendallTau => Value::Number(kendall_tau_distance(list1, list2) as i128),
+        })
+    }
+}
+
+impl FromStr for Metric {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Metric::ALL.into_iter().find(|metric| metric.name() == name).ok_or_else(|| {
+            let names: Vec<&str> = Metric::ALL.iter().map(Metric::name).collect();
+            format!("unknown metric `{name}`, expected one of {}", names.join(", "))
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum Value {
+    Number(i128),
+    Ratio(f64),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Number(number) => write!(f, "{number}"),
+            Value::Ratio(ratio) => write!(f, "{ratio:.6}"),
+        }
+    }
+}
+
+/// Of lists that are already sorted, like `sorted_distance`.
+pub fn squared_distance(list1: &[i64], list2: &[i64]) -> Result<i128, Overflow> {
+    let squares = list1.iter().zip(list2).map(|(&item1, &item2)| {
+        let diff = item1.abs_diff(item2) as i128;
+        diff.checked_mul(diff)
+    });
+    checked_sum("squared distance", squares)
+}
+
+fn counts(list: &[i64]) -> HashMap<i64, u64> {
+    let mut counts = HashMap::new();
+    list.iter().for_each(|&item| *counts.entry(item).or_insert(0) += 1);
+    counts
+}
+
+/// Two empty lists are identical, so their overlap is 1.
+pub fn overlap(list1: &[i64], list2: &[i64]) -> f64 {
+    let (counts1, counts2) = (counts(list1), counts(list2));
+    let shared: u64 = counts1.iter().map(|(item, &count)| count.min(*counts2.get(item).unwrap_or(&0))).sum();
+    let total = list1.len() as u64 + list2.len() as u64 - shared;
+    match total {
+        0 => 1.0,
+        _ => shared as f64 / total as f64,
+    }
+}
+
+/// Counts the lines `i < j` with `list1[i] < list1[j]` but `list2[i] > list2[j]` or the other way
+/// around, ties counting for nothing. Sorting the lines by their left item leaves exactly these pairs
+/// as inversions of the right items, which a merge sort counts in O(n log n).
+pub fn kendall_tau_distance(list1: &[i64], list2: &[i64]) -> u64 {
+    let mut lines: Vec<(i64, i64)> = list1.iter().copied().zip(list2.iter().copied()).collect();
+    // equal left items get their right items in order, so that they don't count as inversions
+    lines.sort_unstable();
+    let mut items: Vec<i64> = lines.into_iter().map(|(_, item2)| item2).collect();
+    count_inversions(&mut items)
+}
+
+fn count_inversions(items: &mut [i64]) -> u64 {
+    if items.len() < 2 {
+        return 0;
+    }
+    let middle = items.len() / 2;
+    let mut inversions = count_inversions(&mut items[..middle]) + count_inversions(&mut items[middle..]);
+    let (left, right) = items.split_at(middle);
+    let mut merged = Vec::with_capacity(items.len());
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() && j < right.len() {
+        if right[j] < left[i] {
+            // everything left in `left` is bigger than `right[j]`
+            inversions += (left.len() - i) as u64;
+            merged.push(right[j]);
+            j += 1;
+        } else {
+            merged.push(left[i]);
+            i += 1;
+        }
+    }
+    merged.extend_from_slice(&left[i..]);
+    merged.extend_from_slice(&right[j..]);
+    items.copy_from_slice(&merged);
+    inversions
+}
+
+/// A pair of the sorted lists, which `Metric::Distance` adds up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PairDiff {
+    /// Position in the sorted lists, from 0
+    pub rank: usize,
+    pub item1: i64,
+    pub item2: i64,
+    pub diff: u64,
+}
+
+/// The pairs of the sorted lists, biggest contribution to the distance first.
+pub fn pair_diffs(list1: &[i64], list2: &[i64]) -> Vec<PairDiff> {
+    let mut diffs: Vec<PairDiff> = list1.iter().copied().zip(list2.iter().copied()).enumerate().map(|(rank, (item1, item2))| {
+        PairDiff { rank, item1, item2, diff: item1.abs_diff(item2) }
+    }).collect();
+    diffs.sort_by(|a, b| b.diff.cmp(&a.diff).then(a.rank.cmp(&b.rank)));
+    diffs
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{metrics::{kendall_tau_distance, overlap, squared_distance, Metric, PairDiff, Value}, LocationLists, Overflow};
+
+    #[test]
+    fn test_metrics() {
+        let lists = LocationLists::try_from(include_str!("../../input/day-01-test")).unwrap();
+        let values: Vec<Value> = Metric::ALL.iter().map(|metric| metric.compute(&lists).unwrap()).collect();
+        // sorted pairs: 1-3, 2-3, 3-3, 3-4, 3-5, 4-9, squared: 4 + 1 + 0 + 1 + 4 + 25
+        assert_eq!(values[..3], [Value::Number(11), Value::Number(31), Value::Number(35)]);
+        // shared: 3 three times, 4 once, out of 12 - 4 distinct items
+        assert_eq!(values[3], Value::Ratio(0.5));
+        assert_eq!(values[3].to_string(), "0.500000");
+        assert_eq!(values[4], Value::Number(5));
+    }
+
+    #[test]
+    fn test_squared_distance_overflow() {
+        assert_eq!(squared_distance(&[-4, 1], &[-1, 3]), Ok(9 + 4));
+        // (2^64 - 1)^2 is just past the largest i128
+        let error = Metric::SquaredDistance.compute(&LocationLists::new(vec![i64::MIN], vec![i64::MAX])).unwrap_err();
+        assert_eq!(error, Overflow { metric: "squared distance" });
+        assert_eq!(error.to_string(), "the squared distance overflows 128 bits");
+    }
+
+    #[test]
+    fn test_overlap() {
+        assert_eq!(overlap(&[], &[]), 1.0);
+        assert_eq!(overlap(&[1, 2], &[3]), 0.0);
+        assert_eq!(overlap(&[1, 1, 2], &[2, 1, 1]), 1.0);
+        assert_eq!(overlap(&[1, 1], &[1]), 0.5);
+    }
+
+    #[test]
+    fn test_kendall_tau_distance() {
+        let brute_force = |list1: &[i64], list2: &[i64]| {
+            let mut discordant = 0;
+            for i in 0..list1.len() {
+                for j in i + 1..list1.len() {
+                    if list1[i].cmp(&list1[j]) == list2[j].cmp(&list2[i]) && list1[i] != list1[j] {
+                        discordant += 1;
+                    }
+                }
+            }
+            discordant
+        };
+        assert_eq!(kendall_tau_distance(&[1, 2, 3], &[1, 2, 3]), 0);
+        assert_eq!(kendall_tau_distance(&[1, 2, 3], &[3, 2, 1]), 3);
+        // only the first and last lines disagree, the others are tied in one of the lists
+        assert_eq!(kendall_tau_distance(&[1, 1, 2], &[5, 4, 4]), 1);
+        let list1 = [3, 4, 2, 1, 3, 3, 7, 0, 7];
+        let list2 = [4, 3, 5, 3, 9, 3, 1, 1, 8];
+        assert_eq!(kendall_tau_distance(&list1, &list2), brute_force(&list1, &list2));
+    }
+
+    #[test]
+    fn test_pair_diffs() {
+        let diffs = LocationLists::new(vec![3, 4, 2, 1, 3, 3], vec![4, 3, 5, 3, 9, 3]).pair_diffs();
+        assert_eq!(diffs[0], PairDiff { rank: 5, item1: 4, item2: 9, diff: 5 });
+        assert_eq!(diffs[1], PairDiff { rank: 0, item1: 1, item2: 3, diff: 2 });
+        assert_eq!(diffs.iter().map(|pair| pair.diff).sum::<u64>(), 11);
+        assert!("hamming".parse::<Metric>().unwrap_err().starts_with("unknown metric `hamming`"));
+        assert_eq!("kendall-tau".parse::<Metric>(), Ok(Metric::KendallTau));
+    }
+}
@@ -0,0 +1,415 @@
+use std::{cmp::Ordering, fmt, str::FromStr};
+use common::{parse::{parse_lines, Cursor}, Answer, ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Increasing,
+    Decreasing,
+    /// Either way, as long as it's the same all along the report
+    Either,
+}
+
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "increasing" => Ok(Direction::Increasing),
+            "decreasing" => Ok(Direction::Decreasing),
+            "either" => Ok(Direction::Either),
+            _ => Err(format!("unknown direction `{name}`, expected `increasing`, `decreasing` or `either`")),
+        }
+    }
+}
+
+/// What makes a report safe: every step between neighbouring levels goes the same way, by
+/// `min_step..=max_step`, or stays put when `allow_equal` is set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SafetyPolicy {
+    pub min_step: u32,
+    pub max_step: u32,
+    pub direction: Direction,
+    pub allow_equal: bool,
+}
+
+impl SafetyPolicy {
+    /// The rules of the puzzle.
+    pub const PUZZLE: SafetyPolicy = SafetyPolicy { min_step: 1, max_step: 3, direction: Direction::Either, allow_equal: false };
+
+    fn allows_step(&self, from: i32, to: i32, increasing: bool) -> bool {
+        match to.cmp(&from) {
+            Ordering::Equal => self.allow_equal,
+            Ordering::Greater if !increasing => false,
+            Ordering::Less if increasing => false,
+            _ => (self.min_step..=self.max_step).contains(&from.abs_diff(to)),
+        }
+    }
+
+    // whether each allowed direction is increasing
+    fn directions(&self) -> &'static [bool] {
+        match self.direction {
+            Direction::Increasing => &[true],
+            Direction::Decreasing => &[false],
+            Direction::Either => &[true, false],
+        }
+    }
+
+    pub fn allows(&self, levels: &[i32]) -> bool {
+        self.directions().iter().any(|&increasing| levels.windows(2).all(|w| self.allows_step(w[0], w[1], increasing)))
+    }
+}
+
+impl Default for SafetyPolicy {
+    fn default() -> Self {
+        Self::PUZZLE
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProblemKind {
+    /// Two neighbours are equal, and the policy doesn't allow it
+    Equal,
+    StepTooSmall,
+    StepTooLarge,
+    /// The levels went one way, and now go the other
+    DirectionChange,
+    /// The levels go the way the policy doesn't allow
+    WrongDirection,
+}
+
+impl ProblemKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ProblemKind::Equal => "equal",
+            ProblemKind::StepTooSmall => "step-too-small",
+            ProblemKind::StepTooLarge => "step-too-large",
+            ProblemKind::DirectionChange => "direction-change",
+            ProblemKind::WrongDirection => "wrong-direction",
+        }
+    }
+}
+
+/// A step between two neighbouring levels that makes a report unsafe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Problem {
+    /// Index of the first of the two levels, from 0
+    pub index: usize,
+    pub from: i32,
+    pub to: i32,
+    pub kind: ProblemKind,
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (from, to, step) = (self.from, self.to, self.from.abs_diff(self.to));
+        write!(f, "levels {} and {} ({from} -> {to}): ", self.index + 1, self.index + 2)?;
+        match self.kind {
+            ProblemKind::Equal => write!(f, "equal"),
+            ProblemKind::StepTooSmall => write!(f, "step of {step} is too small"),
+            ProblemKind::StepTooLarge => write!(f, "step of {step} is too large"),
+            ProblemKind::DirectionChange => write!(f, "direction changes"),
+            ProblemKind::WrongDirection => write!(f, "wrong direction"),
+        }
+    }
+}
+
+/// Why a report is unsafe, and what the Problem Dampener would remove about it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnosis {
+    /// Every offending step, in order
+    pub problems: Vec<Problem>,
+    /// Indices of the levels to remove, `None` if it takes more than the dampener may remove
+    pub removals: Option<Vec<usize>>,
+}
+
+impl Diagnosis {
+    pub fn is_safe(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Report {
+    levels: Vec<i32>
+}
+
+impl Report {
+    pub fn is_safe(&self, policy: &SafetyPolicy) -> bool {
+        policy.allows(&self.levels)
+    }
+
+    /// Checks every step against `policy`. With `Direction::Either`, the first step that isn't flat
+    /// decides which way the report goes.
+    pub fn diagnose(&self, policy: &SafetyPolicy, max_removals: usize) -> Diagnosis {
+        let mut increasing = match policy.direction {
+            Direction::Increasing => Some(true),
+            Direction::Decreasing => Some(false),
+            Direction::Either => None,
+        };
+        let problems = self.levels.windows(2).enumerate().filter_map(|(index, w)| {
+            let (from, to) = (w[0], w[1]);
+            let step = from.abs_diff(to);
+            let kind = match (to.cmp(&from), increasing) {
+                (Ordering::Equal, _) if policy.allow_equal => return None,
+                (Ordering::Equal, _) => ProblemKind::Equal,
+                (Ordering::Greater, Some(false)) | (Ordering::Less, Some(true)) => match policy.direction {
+                    Direction::Either => ProblemKind::DirectionChange,
+                    _ => ProblemKind::WrongDirection,
+                },
+                (order, _) => {
+                    increasing = Some(order == Ordering::Greater);
+                    match step {
+                        _ if step < policy.min_step => ProblemKind::StepTooSmall,
+                        _ if step > policy.max_step => ProblemKind::StepTooLarge,
+                        _ => return None,
+                    }
+                },
+            };
+            Some(Problem { index, from, to, kind })
+        }).collect();
+        Diagnosis { problems, removals: self.dampen(policy, max_removals) }
+    }
+
+    pub fn is_safe_with_dampener(&self, policy: &SafetyPolicy) -> bool {
+        self.dampen(policy, 1).is_some()
+    }
+
+    /// The fewest levels to remove for the report to be safe, as indices, or `None` when that takes
+    /// more than `max_removals`. Nothing to remove means the report is safe as it is. Not quite linear:
+    /// O(n·k) for n levels and k = `max_removals`, k being at most n in effect.
+    pub fn dampen(&self, policy: &SafetyPolicy, max_removals: usize) -> Option<Vec<usize>> {
+        if self.levels.is_empty() {
+            return Some(vec![]);
+        }
+        policy.directions().iter()
+            .filter_map(|&increasing| self.removals(policy, increasing, max_removals))
+            .min_by_key(|removed| removed.len())
+    }
+
+    // Keeps the longest safe subsequence going one way: `fewest[i]` is how many levels up to `i` must go
+    // when `i` stays, and a kept level can only follow one of the `max_removals + 1` before it, so
+    // this is O(n * max_removals).
+    fn removals(&self, policy: &SafetyPolicy, increasing: bool, max_removals: usize) -> Option<Vec<usize>> {
+        let levels = &self.levels;
+        let mut fewest: Vec<usize> = Vec::with_capacity(levels.len());
+        let mut previous: Vec<Option<usize>> = Vec::with_capacity(levels.len());
+        for i in 0..levels.len() {
+            // removing every level before `i` always works
+            let (mut best, mut from) = (i, None);
+            for j in i.saturating_sub(max_removals.saturating_add(1))..i {
+                let removals = fewest[j] + i - j - 1;
+                if removals < best && policy.allows_step(levels[j], levels[i], increasing) {
+                    (best, from) = (removals, Some(j));
+                }
+            }
+            fewest.push(best);
+            previous.push(from);
+        }
+        let (last, total) = fewest.iter().enumerate().map(|(i, removals)| (i, removals + levels.len() - 1 - i))
+            .min_by_key(|&(_, total)| total)?;
+        if total > max_removals {
+            return None;
+        }
+        let mut kept = vec![false; levels.len()];
+        let mut current = Some(last);
+        while let Some(i) = current {
+            kept[i] = true;
+            current = previous[i];
+        }
+        Some((0..levels.len()).filter(|&i| !kept[i]).collect())
+    }
+}
+
+impl Report {
+    fn parse(cursor: &mut Cursor) -> Result<Self, ParseError> {
+        let mut levels = vec![cursor.number::<i32>()?];
+        while cursor.eat(" ") {
+            levels.push(cursor.number::<i32>()?);
+        }
+        Ok(Report{ levels })
+    }
+}
+
+impl TryFrom<&str> for Report {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(1, value);
+        let report = Report::parse(&mut cursor)?;
+        cursor.finish()?;
+        Ok(report)
+    }
+}
+
+pub fn create_reports(input: &str) -> Result<Vec<Report>, ParseError> {
+    parse_lines(input, Report::parse)
+}
+
+pub fn count_safe_reports(reports: Vec<Report>, policy: &SafetyPolicy) -> usize {
+    reports.iter().filter(|report| report.is_safe(policy)).count()
+}
+
+pub fn count_safe_reports_with_dampener(reports: Vec<Report>, policy: &SafetyPolicy) -> usize {
+    reports.iter().filter(|report| report.is_safe_with_dampener(policy)).count()
+}
+
+pub fn count_safe_reports_with_removals(reports: &[Report], policy: &SafetyPolicy, max_removals: usize) -> usize {
+    reports.iter().filter(|report| report.dampen(policy, max_removals).is_some()).count()
+}
+
+pub struct Reports {
+    reports: Vec<Report>,
+    policy: SafetyPolicy,
+    /// How many levels the Problem Dampener may remove in part 2
+    max_removals: usize,
+}
+
+impl Reports {
+    /// Every report, diagnosed with the policy and dampener of part 2.
+    pub fn diagnose(&self) -> Vec<Diagnosis> {
+        self.reports.iter().map(|report| report.diagnose(&self.policy, self.max_removals)).collect()
+    }
+
+    pub fn with_policy(self, policy: SafetyPolicy) -> Self {
+        Self { policy, ..self }
+    }
+
+    pub fn with_max_removals(self, max_removals: usize) -> Self {
+        Self { max_removals, ..self }
+    }
+}
+
+impl Solution for Reports {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self { reports: create_reports(input)?, policy: SafetyPolicy::PUZZLE, max_removals: 1 })
+    }
+
+    fn part1(&self) -> Answer {
+        count_safe_reports(self.reports.clone(), &self.policy).into()
+    }
+
+    fn part2(&self) -> Answer {
+        count_safe_reports_with_removals(&self.reports, &self.policy, self.max_removals).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{count_safe_reports, count_safe_reports_with_dampener, create_reports, Direction, Problem, ProblemKind, Report, SafetyPolicy};
+
+    // every way to remove at most `max_removals` levels, fewest first
+    fn brute_force_dampen(levels: &[i32], policy: &SafetyPolicy, max_removals: usize) -> Option<usize> {
+        (0..1u32 << levels.len()).filter(|removed| removed.count_ones() as usize <= max_removals).filter(|removed| {
+            let kept: Vec<i32> = (0..levels.len()).filter(|i| removed & (1 << i) == 0).map(|i| levels[i]).collect();
+            policy.allows(&kept)
+        }).map(|removed| removed.count_ones() as usize).min()
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../input/day-02-test");
+        let reports = create_reports(input).unwrap();
+        let number_of_safe_reports = count_safe_reports(reports, &SafetyPolicy::PUZZLE);
+        assert_eq!(number_of_safe_reports, 2);
+    }
+    
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../input/day-02-test");
+        let reports = create_reports(input).unwrap();
+        let number_of_safe_reports = count_safe_reports_with_dampener(reports, &SafetyPolicy::PUZZLE);
+        assert_eq!(number_of_safe_reports, 4);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let error = create_reports("7 6 4\n1 2 x 4\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, col 5: expected digit");
+        let error = Report::try_from("1 2 ").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, col 5: expected digit");
+    }
+
+    #[test]
+    fn test_policies() {
+        let report = |levels: &str| Report::try_from(levels).unwrap();
+        let increasing = SafetyPolicy { direction: Direction::Increasing, ..SafetyPolicy::PUZZLE };
+        assert!(report("1 3 6 7 9").is_safe(&increasing));
+        assert!(!report("7 6 4 2 1").is_safe(&increasing));
+        assert!(report("7 6 4 2 1").is_safe(&SafetyPolicy { direction: Direction::Decreasing, ..increasing }));
+
+        let tolerant = SafetyPolicy { max_step: 4, allow_equal: true, ..SafetyPolicy::PUZZLE };
+        assert!(!report("1 2 7 8 9").is_safe(&tolerant));
+        assert!(report("8 6 4 4 1").is_safe(&tolerant));
+        assert!(report("1 5 5 5").is_safe(&tolerant));
+        // still the same way all along
+        assert!(!report("1 3 3 2").is_safe(&tolerant));
+
+        let big_steps = SafetyPolicy { min_step: 2, max_step: 5, ..SafetyPolicy::PUZZLE };
+        assert!(!report("1 3 4").is_safe(&big_steps));
+        assert!(report("1 3 8").is_safe(&big_steps));
+        assert!(report("1 3 4").is_safe_with_dampener(&big_steps));
+        assert_eq!("sideways".parse::<Direction>().unwrap_err(), "unknown direction `sideways`, expected `increasing`, `decreasing` or `either`");
+    }
+
+    #[test]
+    fn test_dampen() {
+        let report = |levels: &str| Report::try_from(levels).unwrap();
+        assert_eq!(report("7 6 4 2 1").dampen(&SafetyPolicy::PUZZLE, 1), Some(vec![]));
+        // removing the 3, or the first 4, would do as well
+        assert_eq!(report("1 3 2 4 5").dampen(&SafetyPolicy::PUZZLE, 1), Some(vec![2]));
+        assert_eq!(report("8 6 4 4 1").dampen(&SafetyPolicy::PUZZLE, 1), Some(vec![3]));
+        assert_eq!(report("1 2 7 8 9").dampen(&SafetyPolicy::PUZZLE, 1), None);
+        assert_eq!(report("9 1 2 3 0 4").dampen(&SafetyPolicy::PUZZLE, 2), Some(vec![0, 4]));
+        assert_eq!(report("9 1 2 3 0 4").dampen(&SafetyPolicy::PUZZLE, 1), None);
+        // as many removals as asked for, without overflowing
+        assert_eq!(report("9 1 2 3 0 4").dampen(&SafetyPolicy::PUZZLE, usize::MAX), Some(vec![0, 4]));
+    }
+
+    #[test]
+    fn test_dampen_against_brute_force() {
+        let policies = [
+            SafetyPolicy::PUZZLE,
+            SafetyPolicy { direction: Direction::Decreasing, allow_equal: true, ..SafetyPolicy::PUZZLE },
+            SafetyPolicy { min_step: 2, max_step: 2, ..SafetyPolicy::PUZZLE },
+        ];
+        // every report of 6 levels between 1 and 4
+        for code in 0..4u32.pow(6) {
+            let levels: Vec<i32> = (0..6).map(|i| (code / 4u32.pow(i) % 4) as i32 + 1).collect();
+            let report = Report { levels: levels.clone() };
+            for policy in &policies {
+                assert_eq!(report.diagnose(policy, 0).is_safe(), policy.allows(&levels), "{levels:?} {policy:?}");
+                for max_removals in 0..=3 {
+                    let removed = report.dampen(policy, max_removals);
+                    assert_eq!(removed.as_ref().map(Vec::len), brute_force_dampen(&levels, policy, max_removals), "{levels:?} {policy:?}");
+                    if let Some(removed) = removed {
+                        let kept: Vec<i32> = (0..6).filter(|i| !removed.contains(i)).map(|i| levels[i]).collect();
+                        assert!(policy.allows(&kept), "{levels:?} without {removed:?}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_diagnose() {
+        let report = |levels: &str| Report::try_from(levels).unwrap();
+        let problems = |levels: &str, policy: &SafetyPolicy| report(levels).diagnose(policy, 1).problems;
+        let problem = |index, from, to, kind| Problem { index, from, to, kind };
+
+        let diagnosis = report("7 6 4 2 1").diagnose(&SafetyPolicy::PUZZLE, 1);
+        assert!(diagnosis.is_safe());
+        assert_eq!(diagnosis.removals, Some(vec![]));
+        assert_eq!(problems("1 2 7 8 9", &SafetyPolicy::PUZZLE), vec![problem(1, 2, 7, ProblemKind::StepTooLarge)]);
+        assert_eq!(problems("1 3 2 4 5", &SafetyPolicy::PUZZLE), vec![problem(1, 3, 2, ProblemKind::DirectionChange)]);
+        assert_eq!(problems("8 6 4 4 1", &SafetyPolicy::PUZZLE), vec![problem(2, 4, 4, ProblemKind::Equal)]);
+        let increasing = SafetyPolicy { direction: Direction::Increasing, min_step: 2, ..SafetyPolicy::PUZZLE };
+        assert_eq!(problems("5 4 6 7", &increasing), vec![
+            problem(0, 5, 4, ProblemKind::WrongDirection),
+            problem(2, 6, 7, ProblemKind::StepTooSmall),
+        ]);
+
+        let diagnosis = report("9 7 6 2 1").diagnose(&SafetyPolicy::PUZZLE, 1);
+        assert_eq!(diagnosis.problems[0].to_string(), "levels 3 and 4 (6 -> 2): step of 4 is too large");
+        assert_eq!(diagnosis.removals, None);
+    }
+}
\ No newline at end of file
@@ -1,84 +1,104 @@
-#[derive(Debug, Clone)]
-struct Report {
-    levels: Vec<i32>
-}
+use std::{env, process};
 
-impl Report {
-    fn is_safe(&self) -> bool {
-        is_levels_vec_safe(&self.levels)
-    }
+use common::{
+    input::InputSource,
+    runner::{parse_args_or_exit, run_with_parser, take_flag, take_option, usage_error},
+    Solution,
+};
+use day_02::{Diagnosis, Reports, SafetyPolicy};
 
-    fn is_safe_with_dampener(&self) -> bool {
-        if is_levels_vec_safe(&self.levels) {
-            true
-        } else {
-            for i in 0..self.levels.len() {
-                let mut shorten_levels = self.levels.clone();
-                shorten_levels.remove(i);
-                if is_levels_vec_safe(&shorten_levels) {
-                    return true;
-                }
-            }
-            false
-        }
-    }
-}
+const POLICY_USAGE: &str = " [--min-step N] [--max-step N] [--direction increasing|decreasing|either] [--allow-equal] [--max-removals N] [--report lines|csv]";
 
-impl From<&str> for Report {
-    fn from(value: &str) -> Self {
-        let levels = value.split(' ').map(|v| v.parse::<i32>().unwrap()).collect();
-        Report{ levels }
+// the puzzle's rules, unless the command line says otherwise
+fn take_policy(args: &mut Vec<String>) -> Result<SafetyPolicy, String> {
+    let mut policy = SafetyPolicy::PUZZLE;
+    if let Some(step) = take_option(args, "--min-step")? {
+        policy.min_step = step.parse().map_err(|_| format!("invalid --min-step `{step}`"))?;
     }
+    if let Some(step) = take_option(args, "--max-step")? {
+        policy.max_step = step.parse().map_err(|_| format!("invalid --max-step `{step}`"))?;
+    }
+    if let Some(direction) = take_option(args, "--direction")? {
+        policy.direction = direction.parse()?;
+    }
+    policy.allow_equal = take_flag(args, "--allow-equal");
+    if policy.min_step > policy.max_step {
+        return Err(format!("--min-step {} is more than --max-step {}", policy.min_step, policy.max_step));
+    }
+    Ok(policy)
 }
 
-fn is_levels_vec_safe(levels: &[i32]) -> bool {
-    let differences: Vec<i32> = levels.windows(2).map(|w| {
-        w[0] - w[1]
-    }).collect();
-    let is_decreasing_within_limits = differences.iter().all(|diff| diff > &0 && diff < &4);
-    let is_increasing_within_limits = differences.iter().all(|diff| diff < &0 && diff > &-4);
-    is_decreasing_within_limits || is_increasing_within_limits
-}
-
-fn create_reports(input: &str) -> Vec<Report> {
-    input.lines().map(|line| line.into()).collect()
+/// Why each report is safe or not, instead of the counts.
+enum Diagnostics {
+    /// A sentence per report
+    Lines,
+    /// A row per problem of the unsafe reports
+    Csv,
 }
 
-fn count_safe_reports(reports: Vec<Report>) -> usize {
-    reports.iter().filter(|report| report.is_safe()).count()
+fn print_lines(diagnoses: &[Diagnosis]) {
+    diagnoses.iter().enumerate().for_each(|(index, diagnosis)| {
+        if diagnosis.is_safe() {
+            println!("line {}: safe", index + 1);
+            return;
+        }
+        let problems: Vec<String> = diagnosis.problems.iter().map(|problem| problem.to_string()).collect();
+        let fix = match &diagnosis.removals {
+            Some(removals) => {
+                let levels: Vec<String> = removals.iter().map(|index| (index + 1).to_string()).collect();
+                format!("safe without level {}", levels.join(", "))
+            },
+            None => "too many levels to remove".to_string(),
+        };
+        println!("line {}: unsafe, {}; {fix}", index + 1, problems.join("; "));
+    });
 }
 
-fn count_safe_reports_with_dampener(reports: Vec<Report>) -> usize {
-    reports.iter().filter(|report| report.is_safe_with_dampener()).count()
+fn print_csv(diagnoses: &[Diagnosis]) {
+    println!("line,level,from,to,problem");
+    diagnoses.iter().enumerate().for_each(|(index, diagnosis)| {
+        diagnosis.problems.iter().for_each(|problem| {
+            println!("{},{},{},{},{}", index + 1, problem.index + 1, problem.from, problem.to, problem.kind.name());
+        });
+    });
 }
 
 fn main() {
-    let input = include_str!("../../input/day-02");
-    let reports = create_reports(input);
-    let number_of_safe_reports = count_safe_reports(reports.clone());
-    println!("Number of safe reports: {number_of_safe_reports}");
-
-    let number_of_safe_reports_with_dampener = count_safe_reports_with_dampener(reports);
-    println!("Number of safe reports with Dampener: {number_of_safe_reports_with_dampener}");
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::{count_safe_reports, count_safe_reports_with_dampener, create_reports};
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let policy = take_policy(&mut args).unwrap_or_else(|error| usage_error(2, &error, POLICY_USAGE));
+    // how many levels the Problem Dampener may remove, one in the puzzle
+    let max_removals = match take_option(&mut args, "--max-removals") {
+        Ok(None) => 1,
+        Ok(Some(count)) => count.parse().unwrap_or_else(|_| usage_error(2, &format!("invalid --max-removals `{count}`"), POLICY_USAGE)),
+        Err(error) => usage_error(2, &error, POLICY_USAGE),
+    };
+    let diagnostics = match take_option(&mut args, "--report") {
+        Ok(None) => None,
+        Ok(Some(name)) => match name.as_str() {
+            "lines" => Some(Diagnostics::Lines),
+            "csv" => Some(Diagnostics::Csv),
+            _ => usage_error(2, &format!("unknown report `{name}`, expected `lines` or `csv`"), POLICY_USAGE),
+        },
+        Err(error) => usage_error(2, &error, POLICY_USAGE),
+    };
+    let args = parse_args_or_exit(2, args);
+    let parse = |input: &str| Reports::parse(input).map(|reports| reports.with_policy(policy).with_max_removals(max_removals));
+    let Some(diagnostics) = diagnostics else {
+        run_with_parser(2, ["Number of safe reports", "Number of safe reports with Dampener"], args, parse);
+        return;
+    };
 
-    #[test]
-    fn test_part1() {
-        let input = include_str!("../../input/day-02-test");
-        let reports = create_reports(input);
-        let number_of_safe_reports = count_safe_reports(reports);
-        assert_eq!(number_of_safe_reports, 2);
+    let source = InputSource::from_arg("day-02", args.input.as_deref());
+    let reports = match source.read().map_err(|error| error.to_string())
+        .and_then(|input| parse(&input).map_err(|error| format!("invalid input: {error}"))) {
+        Ok(reports) => reports,
+        Err(error) => {
+            eprintln!("error: {error}");
+            process::exit(1);
+        },
+    };
+    match diagnostics {
+        Diagnostics::Lines => print_lines(&reports.diagnose()),
+        Diagnostics::Csv => print_csv(&reports.diagnose()),
     }
-    
-    #[test]
-    fn test_part2() {
-        let input = include_str!("../../input/day-02-test");
-        let reports = create_reports(input);
-        let number_of_safe_reports = count_safe_reports_with_dampener(reports);
-        assert_eq!(number_of_safe_reports, 4);
-    }
-}
\ No newline at end of file
+}
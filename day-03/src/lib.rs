@@ -0,0 +1,557 @@
+use std::{io::{self, Read}, ops::Range};
+
+use common::{Answer, ParseError, Solution};
+
+/// What an instruction does when the interpreter runs into it.
+#[derive(Debug, Clone, Copy)]
+pub enum Effect {
+    /// Adds what the function makes of the operands to the total, unless disabled
+    Value(fn(&[u64]) -> i64),
+    Enable,
+    Disable,
+    Toggle,
+}
+
+#[derive(Debug, Clone)]
+pub struct Instruction {
+    pub name: String,
+    pub arity: usize,
+    pub effect: Effect,
+}
+
+/// The instructions to look for in corrupted memory, written `name(a,b,...)` with operands of 1 to 3
+/// digits and no whitespace anywhere.
+#[derive(Debug, Clone)]
+pub struct InstructionSet {
+    instructions: Vec<Instruction>,
+    // the bytes an instruction can start with, to skip the rest quickly
+    first_bytes: [bool; 256],
+    // the longest an instruction can be, operands included
+    max_length: usize,
+}
+
+impl InstructionSet {
+    pub fn new() -> Self {
+        Self { instructions: vec![], first_bytes: [false; 256], max_length: 0 }
+    }
+
+    pub fn with(mut self, name: &str, arity: usize, effect: Effect) -> Self {
+        assert!(!name.is_empty() && !name.contains(['(', ',', ')']), "invalid instruction name `{name}`");
+        assert!(self.instructions.iter().all(|instruction| instruction.name != name), "`{name}` is already registered");
+        self.first_bytes[name.as_bytes()[0] as usize] = true;
+        // `name(`, up to 3 digits per operand, the commas between them and `)`
+        self.max_length = self.max_length.max(name.len() + 2 + 3 * arity + arity.saturating_sub(1));
+        self.instructions.push(Instruction { name: name.to_string(), arity, effect });
+        self
+    }
+
+    /// Part 1 only knows about multiplications.
+    pub fn multiplications() -> Self {
+        Self::new().with("mul", 2, Effect::Value(|operands| (operands[0] * operands[1]) as i64))
+    }
+
+    /// Part 2 can also turn them off and on.
+    pub fn puzzle() -> Self {
+        Self::multiplications().with("do", 0, Effect::Enable).with("don't", 0, Effect::Disable)
+    }
+
+    pub fn tokenize<'a>(&'a self, input: &'a str) -> Tokenizer<'a> {
+        Tokenizer { scanner: self.scan(input) }
+    }
+
+    /// Every candidate instruction in `input`, accepted or not, running the accepted ones to know
+    /// which values are disabled.
+    pub fn candidates<'a>(&'a self, input: &'a str) -> Vec<Candidate<'a>> {
+        let mut interpreter = Interpreter::new();
+        self.scan(input).map(|mut candidate| {
+            if candidate.is_accepted() {
+                if matches!(candidate.instruction.effect, Effect::Value(_)) && !interpreter.is_enabled() {
+                    candidate.rejection = Some(Rejection::Disabled);
+                }
+                interpreter.execute(&Token { instruction: candidate.instruction, operands: candidate.operands.clone() });
+            }
+            candidate
+        }).collect()
+    }
+
+    /// The instructions `reader` yields, read `chunk_size` bytes at a time. Only the end of the last
+    /// chunk is kept around, in case an instruction continues in the next one.
+    pub fn stream<R: Read>(&self, reader: R, chunk_size: usize) -> Stream<'_, R> {
+        assert!(chunk_size > 0, "chunks must not be empty");
+        let buffer = Vec::with_capacity(chunk_size + self.max_length);
+        Stream { set: self, reader, chunk_size, buffer, position: 0, finished: false }
+    }
+
+    fn scan<'a>(&'a self, input: &'a str) -> Scanner<'a, 'a> {
+        Scanner { set: self, input: input.as_bytes(), position: 0 }
+    }
+}
+
+impl Default for InstructionSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Token<'a> {
+    pub instruction: &'a Instruction,
+    pub operands: Vec<u64>,
+}
+
+/// Why a candidate instruction doesn't count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rejection {
+    /// Anything but digits where an operand or separator should be, e.g. `mul(2,-3)` or `mul(2,3]`
+    BadOperand,
+    /// An operand of more than 3 digits
+    TooManyDigits,
+    /// Operands missing or left over, e.g. `mul(2)` or `do(1)`
+    WrongArity,
+    /// A value between a disabling instruction and the next enabling one
+    Disabled,
+}
+
+impl Rejection {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Rejection::BadOperand => "bad operand",
+            Rejection::TooManyDigits => "too many digits",
+            Rejection::WrongArity => "wrong number of operands",
+            Rejection::Disabled => "disabled",
+        }
+    }
+}
+
+/// Anything starting like an instruction, `name(`, whether it turned out to be one or not.
+#[derive(Debug, Clone)]
+pub struct Candidate<'a> {
+    pub instruction: &'a Instruction,
+    /// Byte offsets in the input, up to where the candidate was rejected if it was
+    pub span: Range<usize>,
+    /// The operands read before any rejection
+    pub operands: Vec<u64>,
+    pub rejection: Option<Rejection>,
+}
+
+impl<'a> Candidate<'a> {
+    pub fn is_accepted(&self) -> bool {
+        self.rejection.is_none()
+    }
+}
+
+/// Finds the candidates of a set in a single pass, skipping everything else. It doesn't know about
+/// enabled regions, so only syntax gets candidates rejected.
+struct Scanner<'a, 'i> {
+    set: &'a InstructionSet,
+    input: &'i [u8],
+    position: usize,
+}
+
+impl<'a> Scanner<'a, '_> {
+    fn candidate_at(&self, start: usize) -> Option<Candidate<'a>> {
+        let input = &self.input[start..];
+        let instruction = self.set.instructions.iter()
+            .find(|instruction| input.strip_prefix(instruction.name.as_bytes()).is_some_and(|rest| rest.first() == Some(&b'(')))?;
+        let mut end = start + instruction.name.len() + 1;
+        let mut operands = Vec::with_capacity(instruction.arity);
+        let rejection = self.operands(instruction.arity, &mut end, &mut operands).err();
+        Some(Candidate { instruction, span: start..end, operands, rejection })
+    }
+
+    // reads `a,b,...)` from `end`, leaving `end` after it or where it went wrong
+    fn operands(&self, arity: usize, end: &mut usize, operands: &mut Vec<u64>) -> Result<(), Rejection> {
+        for index in 0..arity {
+            if index > 0 {
+                match self.input.get(*end) {
+                    Some(b',') => *end += 1,
+                    Some(b')') => return Err(Rejection::WrongArity),
+                    _ => return Err(Rejection::BadOperand),
+                }
+            }
+            let digits = self.input[*end..].iter().take_while(|byte| byte.is_ascii_digit()).count();
+            match digits {
+                0 if index == 0 && self.input.get(*end) == Some(&b')') => return Err(Rejection::WrongArity),
+                0 => return Err(Rejection::BadOperand),
+                1..=3 => {},
+                _ => {
+                    *end += digits;
+                    return Err(Rejection::TooManyDigits);
+                },
+            }
+            operands.push(self.input[*end..*end + digits].iter().fold(0, |value, digit| value * 10 + (digit - b'0') as u64));
+            *end += digits;
+        }
+        match self.input.get(*end) {
+            Some(b')') => {
+                *end += 1;
+                Ok(())
+            },
+            Some(b',' | b'0'..=b'9') => Err(Rejection::WrongArity),
+            _ => Err(Rejection::BadOperand),
+        }
+    }
+
+    // the next candidate starting before `limit`
+    fn next_before(&mut self, limit: usize) -> Option<Candidate<'a>> {
+        while self.position < limit {
+            let start = self.position;
+            self.position += 1;
+            if !self.set.first_bytes[self.input[start] as usize] {
+                continue;
+            }
+            if let Some(candidate) = self.candidate_at(start) {
+                // a rejected candidate may hide another one, as in `mul(mul(2,3)`
+                if candidate.is_accepted() {
+                    self.position = candidate.span.end;
+                }
+                return Some(candidate);
+            }
+        }
+        None
+    }
+}
+
+impl<'a> Iterator for Scanner<'a, '_> {
+    type Item = Candidate<'a>;
+
+    fn next(&mut self) -> Option<Candidate<'a>> {
+        self.next_before(self.input.len())
+    }
+}
+
+/// The instructions of a set in some input, in order.
+pub struct Tokenizer<'a> {
+    scanner: Scanner<'a, 'a>,
+}
+
+impl<'a> Iterator for Tokenizer<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        self.scanner.find(Candidate::is_accepted).map(|candidate| Token { instruction: candidate.instruction, operands: candidate.operands })
+    }
+}
+
+/// The instructions of a set in what a reader yields, holding a chunk and the start of an
+/// instruction at most in memory.
+pub struct Stream<'a, R> {
+    set: &'a InstructionSet,
+    reader: R,
+    chunk_size: usize,
+    buffer: Vec<u8>,
+    // where to scan from in `buffer`
+    position: usize,
+    finished: bool,
+}
+
+impl<'a, R: Read> Stream<'a, R> {
+    // drops what has been scanned and appends the next chunk, telling whether there was one
+    fn refill(&mut self) -> io::Result<bool> {
+        self.buffer.drain(..self.position);
+        self.position = 0;
+        let length = self.buffer.len();
+        self.buffer.resize(length + self.chunk_size, 0);
+        let result = loop {
+            match self.reader.read(&mut self.buffer[length..]) {
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                result => break result,
+            }
+        };
+        self.buffer.truncate(length + *result.as_ref().unwrap_or(&0));
+        result.map(|read| read > 0)
+    }
+}
+
+impl<'a, R: Read> Iterator for Stream<'a, R> {
+    type Item = io::Result<Token<'a>>;
+
+    fn next(&mut self) -> Option<io::Result<Token<'a>>> {
+        loop {
+            // an instruction starting this close to the end may continue in the next chunk, so these
+            // starts wait for it, unless there's no next chunk
+            let limit = match self.finished {
+                true => self.buffer.len(),
+                false => (self.buffer.len() + 1).saturating_sub(self.set.max_length.max(1)),
+            };
+            let mut scanner = Scanner { set: self.set, input: &self.buffer, position: self.position };
+            let candidate = std::iter::from_fn(|| scanner.next_before(limit)).find(Candidate::is_accepted);
+            self.position = scanner.position;
+            if let Some(candidate) = candidate {
+                return Some(Ok(Token { instruction: candidate.instruction, operands: candidate.operands }));
+            }
+            if self.finished {
+                return None;
+            }
+            match self.refill() {
+                Ok(more) => self.finished = !more,
+                Err(error) => {
+                    self.finished = true;
+                    self.buffer.clear();
+                    self.position = 0;
+                    return Some(Err(error));
+                },
+            }
+        }
+    }
+}
+
+/// Runs instructions one after the other, keeping track of whether values count.
+#[derive(Debug)]
+pub struct Interpreter {
+    enabled: bool,
+    total: i64,
+}
+
+impl Interpreter {
+    pub fn new() -> Self {
+        Self { enabled: true, total: 0 }
+    }
+
+    pub fn execute(&mut self, token: &Token) {
+        match token.instruction.effect {
+            Effect::Value(value) if self.enabled => self.total += value(&token.operands),
+            Effect::Value(_) => {},
+            Effect::Enable => self.enabled = true,
+            Effect::Disable => self.enabled = false,
+            Effect::Toggle => self.enabled = !self.enabled,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn total(&self) -> i64 {
+        self.total
+    }
+}
+
+impl Default for Interpreter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn evaluate<'a>(tokens: impl IntoIterator<Item = Token<'a>>) -> i64 {
+    let mut interpreter = Interpreter::new();
+    tokens.into_iter().for_each(|token| interpreter.execute(&token));
+    interpreter.total()
+}
+
+pub fn multiply(input: &str) -> i64 {
+    evaluate(InstructionSet::multiplications().tokenize(input))
+}
+
+/// Like `multiply`, ignoring the multiplications between a `don't()` and the next `do()`.
+pub fn multiply_enabled_only(input: &str) -> i64 {
+    evaluate(InstructionSet::puzzle().tokenize(input))
+}
+
+/// Both parts in a single pass over `reader`, for inputs too big to hold in memory.
+pub fn multiply_stream(reader: impl Read, chunk_size: usize) -> io::Result<[i64; 2]> {
+    let (mut all, mut enabled) = (Interpreter::new(), Interpreter::new());
+    for token in InstructionSet::puzzle().stream(reader, chunk_size) {
+        let token = token?;
+        // `do()` and `don't()` can't hide a multiplication, so these are the ones part 1 finds
+        if matches!(token.instruction.effect, Effect::Value(_)) {
+            all.execute(&token);
+        }
+        enabled.execute(&token);
+    }
+    Ok([all.total(), enabled.total()])
+}
+
+const HIGHLIGHT: &str = "\x1b[1;32m";
+const RESET: &str = "\x1b[0m";
+
+/// The input with the accepted candidates in bold green, for a terminal.
+pub fn highlight(input: &str, candidates: &[Candidate]) -> String {
+    let mut highlighted = String::with_capacity(input.len());
+    let mut position = 0;
+    for candidate in candidates.iter().filter(|candidate| candidate.is_accepted()) {
+        highlighted.push_str(&input[position..candidate.span.start]);
+        highlighted.push_str(HIGHLIGHT);
+        highlighted.push_str(&input[candidate.span.clone()]);
+        highlighted.push_str(RESET);
+        position = candidate.span.end;
+    }
+    highlighted.push_str(&input[position..]);
+    highlighted
+}
+
+pub struct CorruptedMemory {
+    memory: String,
+}
+
+impl Solution for CorruptedMemory {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self { memory: input.to_string() })
+    }
+
+    fn part1(&self) -> Answer {
+        multiply(&self.memory).into()
+    }
+
+    fn part2(&self) -> Answer {
+        multiply_enabled_only(&self.memory).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Read};
+    use crate::{evaluate, highlight, multiply, multiply_enabled_only, multiply_stream, Effect, InstructionSet, Rejection, Token};
+
+    fn names<'a>(tokens: impl Iterator<Item = Token<'a>>) -> Vec<String> {
+        tokens.map(|token| format!("{}{:?}", token.instruction.name, token.operands)).collect()
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../input/day-03-test");
+        let result = multiply(input);
+        assert_eq!(result, 161);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let invalid_input = "mul(4*";
+        let result = multiply(invalid_input);
+        assert_eq!(result, 0);
+
+        let invalid_input = "mul(6,9!";
+        let result = multiply(invalid_input);
+        assert_eq!(result, 0);
+
+        let invalid_input = "?(12,34)";
+        let result = multiply(invalid_input);
+        assert_eq!(result, 0);
+
+        let invalid_input = "mul ( 2 , 4 )";
+        let result = multiply(invalid_input);
+        assert_eq!(result, 0);
+
+        let invalid_input = "mul( 2 , 4 )";
+        let result = multiply(invalid_input);
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn test_adjacent_muls() {
+        let input = "mul(6,9)mul(23,2)";
+        let result = multiply(input);
+        assert_eq!(result, 100);
+    }
+    
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../input/day-03-test-part2");
+        let result = multiply_enabled_only(input);
+        assert_eq!(result, 48);
+    }
+
+    #[test]
+    fn test_tokenize() {
+        let set = InstructionSet::puzzle();
+        let tokens = set.tokenize("mul(mul(1,2)don't()mul(1234,5)mul(12,345)do()_mul(7,8))do(");
+        assert_eq!(names(tokens), vec!["mul[1, 2]", "don't[]", "mul[12, 345]", "do[]", "mul[7, 8]"]);
+        assert_eq!(set.tokenize("mul(,2)mul(2,)mul(-2,3)mul(2,3]mul(2,3,4)do(1)").count(), 0);
+        assert_eq!(names(InstructionSet::multiplications().tokenize("do()mul(2,3)don't()")), vec!["mul[2, 3]"]);
+    }
+
+    #[test]
+    fn test_custom_instructions() {
+        let set = InstructionSet::new()
+            .with("add", 2, Effect::Value(|operands| (operands[0] + operands[1]) as i64))
+            .with("sub", 2, Effect::Value(|operands| operands[0] as i64 - operands[1] as i64))
+            .with("sum", 3, Effect::Value(|operands| operands.iter().sum::<u64>() as i64))
+            .with("toggle", 0, Effect::Toggle);
+        let input = "add(1,2)xsub(1,10)toggle()add(100,100)toggle()sum(1,2,3)mul(5,5)";
+        assert_eq!(names(set.tokenize(input)), vec!["add[1, 2]", "sub[1, 10]", "toggle[]", "add[100, 100]", "toggle[]", "sum[1, 2, 3]"]);
+        assert_eq!(evaluate(set.tokenize(input)), 3 - 9 + 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "`mul` is already registered")]
+    fn test_duplicate_instruction() {
+        InstructionSet::multiplications().with("mul", 3, Effect::Enable);
+    }
+
+    #[test]
+    fn test_candidates() {
+        let set = InstructionSet::puzzle();
+        let input = "mul(mul(1,2)mul(1234,5)don't()mul(2,3)mul(2)do(1)mul(2,3,4)do()mul(-1,2)mul(3,4]";
+        let candidates: Vec<String> = set.candidates(input).iter().map(|candidate| {
+            let status = candidate.rejection.map_or("accepted", |rejection| rejection.name());
+            format!("{:?} {}{:?} {status}", candidate.span, candidate.instruction.name, candidate.operands)
+        }).collect();
+        assert_eq!(candidates, vec![
+            "0..4 mul[] bad operand",
+            "4..12 mul[1, 2] accepted",
+            "12..20 mul[] too many digits",
+            "23..30 don't[] accepted",
+            "30..38 mul[2, 3] disabled",
+            "38..43 mul[2] wrong number of operands",
+            "44..47 do[] wrong number of operands",
+            "49..56 mul[2, 3] wrong number of operands",
+            "59..63 do[] accepted",
+            "63..67 mul[] bad operand",
+            "72..79 mul[3, 4] bad operand",
+        ]);
+        // the part 1 rules have no disabled regions
+        let set = InstructionSet::multiplications();
+        let candidates = set.candidates("don't()mul(2,3)");
+        assert_eq!(candidates.len(), 1);
+        assert!(candidates[0].is_accepted());
+        assert_eq!(Rejection::Disabled.name(), "disabled");
+    }
+
+    #[test]
+    fn test_highlight() {
+        let set = InstructionSet::puzzle();
+        let input = "xmul(2,4)&don't()_mul(5,5)";
+        let highlighted = highlight(input, &set.candidates(input));
+        assert_eq!(highlighted, "x\x1b[1;32mmul(2,4)\x1b[0m&\x1b[1;32mdon't()\x1b[0m_mul(5,5)");
+    }
+
+    #[test]
+    fn test_stream() {
+        let input = [
+            include_str!("../../input/day-03-test"),
+            include_str!("../../input/day-03-test-part2"),
+            "mul(12,34)don't()mul(1,1)do()mul(999,999)mul(1234,5)mul(mul(7,8)do(don't()do()mul(2,3)",
+        ].concat();
+        let expected = [multiply(&input), multiply_enabled_only(&input)];
+        // every chunk size splits some instruction somewhere, down to one byte at a time
+        for chunk_size in (1..=40).chain([64, 4096]) {
+            assert_eq!(multiply_stream(input.as_bytes(), chunk_size).unwrap(), expected, "chunks of {chunk_size}");
+        }
+        let set = InstructionSet::puzzle();
+        let mut stream = set.stream(input.as_bytes(), 5);
+        assert_eq!(stream.by_ref().count(), set.tokenize(&input).count());
+        // the chunk and the start of an instruction, never the whole input
+        assert!(stream.buffer.capacity() <= 5 + set.max_length, "{}", stream.buffer.capacity());
+    }
+
+    #[test]
+    fn test_stream_error() {
+        struct Failing<'a>(&'a [u8]);
+
+        impl Read for Failing<'_> {
+            fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+                match self.0.is_empty() {
+                    true => Err(io::Error::other("disk on fire")),
+                    false => self.0.read(buffer),
+                }
+            }
+        }
+
+        let set = InstructionSet::puzzle();
+        let results: Vec<String> = set.stream(Failing(b"mul(2,3)xmul(4,"), 4).map(|token| match token {
+            Ok(token) => format!("{}{:?}", token.instruction.name, token.operands),
+            Err(error) => error.to_string(),
+        }).collect();
+        assert_eq!(results, vec!["mul[2, 3]", "disk on fire"]);
+    }
+}
\ No newline at end of file
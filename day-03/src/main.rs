@@ -1,135 +1,82 @@
-fn trim_until_mul(input: String) -> Option<String> {
-    input.find("mul(").map(|index| input[(index+4)..].to_string())
-}
+use std::{env, process, time::Instant};
 
-fn process_multiplication_candidate(input: String) -> (u64, String) {
-    if let Some(comma_index) = input.find(',') {
-        if let Some(mul_index) = input.find("mul(") {
-            if mul_index < comma_index {
-                let next_input = input[mul_index..].to_string();
-                println!("failed to find a multiplication end at {}", &input[0..comma_index]);
-                return (0, next_input);
-            }
-        }
-        let forward_input = input[(comma_index+1)..].to_string();
-        let factor1 = match input[0..comma_index].parse::<u64>() {
-            Ok(number) => number,
-            Err(_) => {
-                println!("failed to find a first factor at {}", &input[0..comma_index]);
-                return (0, forward_input);
-            },
-        };
-        if let Some(par_index) = forward_input.find(')') {
-            if let Some(mul_index) = forward_input.find("mul(") {
-                if mul_index < par_index {
-                    let next_input = forward_input[mul_index..].to_string();
-                    println!("failed to find a multiplication end at {}", &forward_input[0..par_index]);
-                    return (0, next_input);
-                }
-            }
-            let forward_input2 = forward_input[(par_index+1)..].to_string();
-            let factor2 = match forward_input[0..par_index].parse::<u64>() {
-                Ok(number) => number,
-                Err(_) => {
-                    println!("failed to find a second factor at {}", &forward_input[0..par_index]);
-                    return (0, forward_input2);
-                },
-            };
-            (factor1*factor2, forward_input2)
-        } else {
-            (0, "".to_string())
-        }
-    }
-    else {
-        (0, "".to_string())
-    }
-}
+use common::{
+    input::InputSource,
+    runner::{json_line, parse_args_or_exit, run_with_args, take_flag, take_option, usage_error, Args, Format},
+    Answer,
+};
+use day_03::{highlight, multiply_stream, CorruptedMemory, InstructionSet};
 
-fn multiply(mut input: String) -> u64 {
-    let mut result = 0;
-    let mut count = 0;
-    while let Some(inp) = trim_until_mul(input) {
-        let (number, new_input) = process_multiplication_candidate(inp);
-        if number > 0 {
-            count += 1;
-        }
-        result += number;
-        input = new_input;
-    }
-    println!("{count} multiplications");
-    result
+const REPORT_USAGE: &str = " [--report candidates|highlight] [--stream]";
+const LABELS: [&str; 2] = ["Result part 1", "Result part 2"];
+// how much of the input `--stream` reads at once
+const CHUNK_SIZE: usize = 1 << 16;
+
+/// How the input was read, under the part 2 rules, instead of the results.
+enum Report {
+    /// A line per candidate instruction, with its offsets and whether it counts
+    Candidates,
+    /// The input itself, accepted instructions highlighted
+    Highlight,
 }
 
-fn multiply_enabled_only(input: String) -> u64 {
-    if let Some(first_dont) = input.find("don't()") {
-        let first_multiply = multiply(input[..first_dont].to_string());
-        first_multiply + input.split("don't()").map(|inp| {
-            if let Some(index) = inp.find("do()") {
-                multiply(inp[(index + 4)..].to_string())
-            } else {
-                0
-            }
-        }).sum::<u64>()
-    } else {
-        multiply(input)
+/// Both parts without loading the whole input, for dumps bigger than memory.
+fn run_stream(args: Args) {
+    let source = InputSource::from_arg("day-03", args.input.as_deref());
+    let start = Instant::now();
+    let results = source.open().map_err(|error| error.to_string())
+        .and_then(|reader| multiply_stream(reader, CHUNK_SIZE).map_err(|error| format!("cannot read {source}: {error}")));
+    let results = results.unwrap_or_else(|error| {
+        eprintln!("error: {error}");
+        process::exit(1);
+    });
+    // both parts come out of the same pass, which is what they report as their time
+    let elapsed = start.elapsed();
+    for (part, result) in [1, 2].into_iter().zip(results) {
+        let answer = Answer::from(result);
+        match args.format {
+            Format::Text => println!("{}: {answer}", LABELS[part as usize - 1]),
+            Format::Json => println!("{}", json_line(3, part, &answer, elapsed)),
+        }
     }
 }
 
 fn main() {
-    let input = include_str!("../../input/day-03");
-    let result = multiply(input.to_string());
-    println!("Result part 1: {result}");
-
-    let result = multiply_enabled_only(input.to_string());
-    println!("Result part 2: {result}");
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::{multiply, multiply_enabled_only};
-
-
-    #[test]
-    fn test_part1() {
-        let input = include_str!("../../input/day-03-test");
-        let result = multiply(input.to_string());
-        assert_eq!(result, 161);
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let stream = take_flag(&mut args, "--stream");
+    let report = match take_option(&mut args, "--report") {
+        Ok(None) => None,
+        Ok(Some(name)) => match name.as_str() {
+            "candidates" => Some(Report::Candidates),
+            "highlight" => Some(Report::Highlight),
+            _ => usage_error(3, &format!("unknown report `{name}`, expected `candidates` or `highlight`"), REPORT_USAGE),
+        },
+        Err(error) => usage_error(3, &error, REPORT_USAGE),
+    };
+    if stream && report.is_some() {
+        usage_error(3, "--report needs the whole input, it doesn't go with --stream", REPORT_USAGE);
     }
-
-    #[test]
-    fn test_invalid() {
-        let invalid_input = "mul(4*";
-        let result = multiply(invalid_input.to_string());
-        assert_eq!(result, 0);
-
-        let invalid_input = "mul(6,9!";
-        let result = multiply(invalid_input.to_string());
-        assert_eq!(result, 0);
-
-        let invalid_input = "?(12,34)";
-        let result = multiply(invalid_input.to_string());
-        assert_eq!(result, 0);
-
-        let invalid_input = "mul ( 2 , 4 )";
-        let result = multiply(invalid_input.to_string());
-        assert_eq!(result, 0);
-
-        let invalid_input = "mul( 2 , 4 )";
-        let result = multiply(invalid_input.to_string());
-        assert_eq!(result, 0);
+    let args = parse_args_or_exit(3, args);
+    if stream {
+        return run_stream(args);
     }
+    let Some(report) = report else {
+        run_with_args::<CorruptedMemory>(3, LABELS, args);
+        return;
+    };
 
-    #[test]
-    fn test_adjacent_muls() {
-        let input = "mul(6,9)mul(23,2)";
-        let result = multiply(input.to_string());
-        assert_eq!(result, 100);
+    let input = InputSource::from_arg("day-03", args.input.as_deref()).read().unwrap_or_else(|error| {
+        eprintln!("error: {error}");
+        process::exit(1);
+    });
+    let set = InstructionSet::puzzle();
+    let candidates = set.candidates(&input);
+    match report {
+        Report::Candidates => candidates.iter().for_each(|candidate| {
+            let operands: Vec<String> = candidate.operands.iter().map(|operand| operand.to_string()).collect();
+            let status = candidate.rejection.map_or("accepted", |rejection| rejection.name());
+            println!("{}..{}: {}({}) {status}", candidate.span.start, candidate.span.end, candidate.instruction.name, operands.join(","));
+        }),
+        Report::Highlight => print!("{}", highlight(&input, &candidates)),
     }
-    
-    #[test]
-    fn test_part2() {
-        let input = include_str!("../../input/day-03-test-part2");
-        let result = multiply_enabled_only(input.to_string());
-        assert_eq!(result, 48);
-    }
-}
\ No newline at end of file
+}
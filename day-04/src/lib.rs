@@ -0,0 +1,148 @@
+use common::{Answer, ParseError, Solution};
+use grid::{Grid, Heading, Position};
+
+/// Where a word was found: its first letter, and the way the others follow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    pub start: Position,
+    pub heading: Heading,
+}
+
+pub struct WordSearch {
+    grid: Grid<char>,
+}
+
+impl TryFrom<&str> for WordSearch {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let grid = Grid::parse(input, |c| match c.is_whitespace() {
+            true => Err(ParseError::new("unexpected whitespace in the grid")),
+            false => Ok(c),
+        })?;
+        Ok(WordSearch { grid })
+    }
+}
+
+impl WordSearch {
+    /// Every occurrence of `word`, in any of the 8 headings and overlapping or not, walking the grid
+    /// from each cell holding its first letter. A single letter has no heading to speak of, it matches
+    /// once per cell, going north.
+    pub fn find(&self, word: &str) -> Vec<Match> {
+        let letters: Vec<char> = word.chars().collect();
+        let Some((first, rest)) = letters.split_first() else {
+            return vec![];
+        };
+        let headings: &[Heading] = match rest.is_empty() {
+            true => &[Heading::North],
+            false => &Heading::ALL,
+        };
+        self.grid.iter().filter(|(_, c)| *c == first).flat_map(|(start, _)| {
+            headings.iter().filter(move |heading| self.spells(start, **heading, rest))
+                .map(move |&heading| Match { start, heading })
+        }).collect()
+    }
+
+    // whether `letters` follow `start` in the grid, going `heading`
+    fn spells(&self, start: Position, heading: Heading, letters: &[char]) -> bool {
+        let (dx, dy) = heading.offset();
+        let mut position = start;
+        letters.iter().all(|letter| {
+            match position.offset(dx, dy).filter(|next| self.grid.get(*next) == Some(letter)) {
+                Some(next) => {
+                    position = next;
+                    true
+                },
+                None => false,
+            }
+        })
+    }
+
+    pub fn count_xmas(&self) -> usize {
+        self.find("XMAS").len()
+    }
+
+    pub fn count_x_mas(&self) -> usize {
+        self.grid.iter().filter(|(position, c)|
+            **c == 'A' && self.is_x_mas(*position)
+        ).count()
+    }
+
+    fn is_x_mas(&self, position: Position) -> bool {
+        let corner = |dx, dy| position.offset(dx, dy).and_then(|corner| self.grid.get(corner)).copied();
+        let is_mas = |end1, end2| matches!((end1, end2), (Some('S'), Some('M')) | (Some('M'), Some('S')));
+        is_mas(corner(-1, -1), corner(1, 1)) && is_mas(corner(1, -1), corner(-1, 1))
+    }
+}
+
+impl Solution for WordSearch {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        self.count_xmas().into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.count_x_mas().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use grid::{Heading, Position};
+    use crate::{Match, WordSearch};
+
+    #[test]
+    fn test_part1_horizontal() {
+        let input = include_str!("../../input/day-04-test");
+        let lines = WordSearch::try_from(input).unwrap();
+        let count_horizontal = lines.find("XMAS").iter().filter(|found| matches!(found.heading, Heading::East | Heading::West)).count();
+        assert_eq!(count_horizontal, 5);
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../input/day-04-test");
+        let lines = WordSearch::try_from(input).unwrap();
+        let count = lines.count_xmas();
+        assert_eq!(count, 18);
+    }
+    
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../input/day-04-test");
+        let lines = WordSearch::try_from(input).unwrap();
+        let count = lines.count_x_mas();
+        assert_eq!(count, 9);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let error = WordSearch::try_from("XMAS\nXMA\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 2, col 4: expected 4 cells on this row, found 3");
+        let error = WordSearch::try_from("XMAS\nXM S\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 2, col 3: unexpected whitespace in the grid");
+    }
+
+    #[test]
+    fn test_find() {
+        // `str::matches` would only find the first of these two, they share the X
+        let search = WordSearch::try_from("XMASAMX").unwrap();
+        assert_eq!(search.find("XMAS"), vec![
+            Match { start: Position::new(0, 0), heading: Heading::East },
+            Match { start: Position::new(6, 0), heading: Heading::West },
+        ]);
+        // any word in any alphabet, here running down and up the diagonals
+        let search = WordSearch::try_from("λ..\n.μ.\n..λ").unwrap();
+        assert_eq!(search.find("λμλ"), vec![
+            Match { start: Position::new(0, 0), heading: Heading::SouthEast },
+            Match { start: Position::new(2, 2), heading: Heading::NorthWest },
+        ]);
+        assert_eq!(search.find("μ"), vec![Match { start: Position::new(1, 1), heading: Heading::North }]);
+        assert_eq!(search.find(".").len(), 6);
+        assert!(search.find("").is_empty());
+        assert!(search.find("λμλμ").is_empty());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,135 @@
+use std::{cmp::Ordering, collections::HashMap};
+use common::{parse::{lines, Cursor}, Answer, ParseError, Solution};
+
+#[derive(Debug)]
+pub struct Instructions {
+    forbidden_after: HashMap<u32, Vec<u32>>,
+    pages: Vec<Pages>,
+}
+
+impl Instructions {
+    pub fn sum_valid_pages_numbers(&self) -> u32 {
+        self.pages.iter().filter(|pages| pages.is_valid(&self.forbidden_after)).map(|pages| pages.middle_page_number()).sum()
+    }
+
+    pub fn sum_fixed_invalid_pages_numbers(&self) -> u32 {
+        let mut invalid_pages: Vec<Pages> = self.pages.iter().filter(|pages| !pages.is_valid(&self.forbidden_after)).cloned().collect();
+
+        invalid_pages.iter_mut().for_each(|pages| {
+            pages.pages.sort_by(|a, b| {
+                match self.forbidden_after.get(b) {
+                    Some(list) => {
+                        if list.contains(a) {
+                            Ordering::Less
+                        } else {
+                            Ordering::Equal
+                        }
+                    },
+                    None => Ordering::Equal,
+                }
+            });
+        });
+        invalid_pages.iter().map(|pages| pages.middle_page_number()).sum()
+    }
+}
+
+impl TryFrom<&str> for Instructions {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut lines = lines(input);
+        let mut forbidden_after: HashMap<u32, Vec<u32>> = HashMap::new();
+        for mut cursor in lines.by_ref().take_while(|cursor| !cursor.is_at_end()) {
+            let before = cursor.number::<u32>()?;
+            cursor.expect("|")?;
+            let after = cursor.number::<u32>()?;
+            cursor.finish()?;
+            forbidden_after.entry(after).and_modify(|rule| rule.push(before)).or_insert(vec![before]);
+        }
+
+        let pages = lines.map(|mut cursor| Pages::parse(&mut cursor)).collect::<Result<_, _>>()?;
+        Ok(Self { forbidden_after, pages })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Pages {
+    pub pages: Vec<u32>
+}
+
+impl Pages {
+    fn is_valid(&self, forbidden_after: &HashMap<u32, Vec<u32>>) -> bool {
+        let mut forbidden_pages = vec![];
+        for page in &self.pages {
+            if forbidden_pages.contains(&page) {
+                // println!("{:?} is invalid", self.pages);
+                // println!("current number is {page}");
+                // println!("current interdictions are {:?}", forbidden_pages);
+                return false;
+            } else if let Some(forbidden) = forbidden_after.get(page) {
+                forbidden.iter().for_each(|f| forbidden_pages.push(f));
+            }
+        }
+        // println!("{:?} is valid", self.pages);
+        true
+    }
+
+    fn middle_page_number(&self) -> u32 {
+        let length = self.pages.len();
+        self.pages[length / 2]
+    }
+}
+
+impl Pages {
+    fn parse(cursor: &mut Cursor) -> Result<Self, ParseError> {
+        let mut pages = vec![cursor.number::<u32>()?];
+        while cursor.eat(",") {
+            pages.push(cursor.number::<u32>()?);
+        }
+        cursor.finish()?;
+        Ok(Self { pages })
+    }
+}
+
+impl Solution for Instructions {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        self.sum_valid_pages_numbers().into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.sum_fixed_invalid_pages_numbers().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Instructions;
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../input/day-05-test");
+        let instructions = Instructions::try_from(input).unwrap();
+        let sum = instructions.sum_valid_pages_numbers();
+        assert_eq!(sum, 143);
+    }
+    
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../input/day-05-test");
+        let instructions = Instructions::try_from(input).unwrap();
+        let sum = instructions.sum_fixed_invalid_pages_numbers();
+        assert_eq!(sum, 123);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let error = Instructions::try_from("47|53\n97-13\n\n75,47\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, col 3: expected `|`");
+        let error = Instructions::try_from("47|53\n\n75,47,\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 3, col 7: expected digit");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,142 @@
+use common::{Answer, ParseError, Solution};
+use grid::{Direction, Grid, Position};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Guard {
+    position: Position,
+    direction: Direction,
+}
+
+#[derive(Debug, Clone)]
+pub struct LabMap {
+    obstacles: Grid<bool>,
+    guard_start: Guard,
+}
+
+impl LabMap {
+    // walks straight until the next obstacle (or the extra `obstruction`), or out of the map
+    fn next(&self, guard: Guard, obstruction: Option<Position>) -> (Vec<Position>, Option<Guard>) {
+        let mut position = guard.position;
+        let mut visited_cells = vec![position];
+        while let Some(next_position) = self.obstacles.step(position, guard.direction) {
+            if self.obstacles[next_position] || obstruction == Some(next_position) {
+                return (visited_cells, Some(Guard { position, direction: guard.direction.turn_right() }));
+            }
+            position = next_position;
+            visited_cells.push(position);
+        }
+        (visited_cells, None)
+    }
+
+    pub fn count_positions(&self) -> (usize, Vec<Position>) {
+        let mut guard = self.guard_start;
+        let mut visited_positions = vec![self.guard_start.position];
+        loop {
+            let (mut new_positions, new_guard) = self.next(guard, None);
+            visited_positions.append(&mut new_positions);
+            if let Some(new_guard) = new_guard {
+                guard = new_guard;
+            } else {
+                break;
+            }
+        }
+        visited_positions.sort();
+        visited_positions.dedup();
+        let count = visited_positions.len();
+        (count, visited_positions)
+    }
+
+    fn does_cycle(&self, obstruction: Position) -> bool {
+        let mut visited_positions = vec![];
+        let mut guard = self.guard_start;
+        loop {
+            let (new_positions, new_guard) = self.next(guard, Some(obstruction));
+            for position in new_positions {
+                let pos_with_dir = (position, guard.direction);
+                if visited_positions.contains(&pos_with_dir) {
+                    return true;
+                } else {
+                    visited_positions.push(pos_with_dir);
+                }
+            }
+            if let Some(new_guard) = new_guard {
+                guard = new_guard;
+            } else {
+                break;
+            }
+        }
+        false
+    }
+
+    pub fn brute_force_obstructions(&self, visited: Vec<Position>) -> usize {
+        let start_position = self.guard_start.position;
+        #[cfg(feature = "parallel")]
+        let candidates = visited.into_par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let candidates = visited.into_iter();
+        candidates.filter(|&position| position != start_position)
+        .filter(|&position| self.does_cycle(position)).count()
+    }
+}
+
+impl TryFrom<&str> for LabMap {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let grid = Grid::parse(input, |c| match c {
+            '.' | '#' | '^' => Ok(c),
+            _ => Err(ParseError::new(format!("unexpected `{c}`, expected `.`, `#` or `^`"))),
+        })?;
+        let position = grid.find_unique(|c| c == &'^', "guard `^`")?;
+        let obstacles = grid.map(|c| c == &'#');
+        Ok(LabMap { obstacles, guard_start: Guard { position, direction: Direction::North } })
+    }
+}
+
+impl Solution for LabMap {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        let (count, _) = self.count_positions();
+        count.into()
+    }
+
+    fn part2(&self) -> Answer {
+        let (_, visited) = self.count_positions();
+        self.brute_force_obstructions(visited).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LabMap;
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../input/day-06-test");
+        let lab_map = LabMap::try_from(input).unwrap();
+        let (count, _) = lab_map.count_positions();
+        assert_eq!(count, 41);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../input/day-06-test");
+        let lab_map = LabMap::try_from(input).unwrap();
+        let (_, visited) = lab_map.count_positions();
+        let count = lab_map.brute_force_obstructions(visited);
+        assert_eq!(count, 6);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let error = LabMap::try_from("..#\n.^.\n.O.\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 3, col 2: unexpected `O`, expected `.`, `#` or `^`");
+        let error = LabMap::try_from("..#\n...\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 3, col 1: unexpected end of input, no guard `^` found");
+    }
+}
\ No newline at end of file
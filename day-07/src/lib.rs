@@ -0,0 +1,162 @@
+use common::{parse::{parse_lines, Cursor}, Answer, ParseError, Solution};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+#[derive(Debug)]
+struct Equation {
+    result: u64,
+    numbers: Vec<u64>
+}
+
+impl Equation {
+    fn is_valid(&self) -> bool {
+        let index = 1;
+        let init = self.numbers[0];
+        let next_number = self.numbers[index];
+        // a value past u64 is past the result too, so it's pruned like one
+        init.checked_add(next_number).is_some_and(|value| self.is_valid_rec(index, value))
+            || init.checked_mul(next_number).is_some_and(|value| self.is_valid_rec(index, value))
+    }
+
+    fn is_valid_rec(&self, index: usize, value: u64) -> bool {
+        if index == self.numbers.len() - 1 {
+            value == self.result
+        } else if value > self.result {
+            false
+        } else {
+            let new_index = index + 1;
+            let next_number = self.numbers[new_index];
+            value.checked_add(next_number).is_some_and(|value| self.is_valid_rec(new_index, value))
+                || value.checked_mul(next_number).is_some_and(|value| self.is_valid_rec(new_index, value))
+        }
+    }
+
+    fn is_valid_with_concatenation(&self) -> bool {
+        let index = 1;
+        let init = self.numbers[0];
+        let next_number = self.numbers[index];
+        [init.checked_add(next_number), init.checked_mul(next_number), concat(init, next_number)]
+            .into_iter().flatten().any(|value| self.is_valid_with_concatenation_rec(index, value))
+    }
+
+    fn is_valid_with_concatenation_rec(&self, index: usize, value: u64) -> bool {
+        if index == self.numbers.len() - 1 {
+            value == self.result
+        } else if value > self.result {
+            false
+        } else {
+            let new_index = index + 1;
+            let next_number = self.numbers[new_index];
+            [value.checked_add(next_number), value.checked_mul(next_number), concat(value, next_number)]
+                .into_iter().flatten().any(|value| self.is_valid_with_concatenation_rec(new_index, value))
+        }
+    }
+}
+
+// the digits of `a` followed by those of `b`, `None` past u64
+fn concat(a: u64, b: u64) -> Option<u64> {
+    let digits = b.checked_ilog10().unwrap_or(0) + 1;
+    a.checked_mul(10u64.checked_pow(digits)?)?.checked_add(b)
+}
+
+impl Equation {
+    fn parse(cursor: &mut Cursor) -> Result<Self, ParseError> {
+        let result = cursor.number::<u64>()?;
+        cursor.expect(": ")?;
+        let mut numbers = vec![cursor.number::<u64>()?];
+        // the solvers always combine at least two numbers
+        cursor.expect(" ")?;
+        numbers.push(cursor.number::<u64>()?);
+        while cursor.eat(" ") {
+            numbers.push(cursor.number::<u64>()?);
+        }
+        Ok(Self { result, numbers })
+    }
+}
+
+pub struct Equations {
+    equations: Vec<Equation>,
+}
+
+impl Equations {
+    pub fn calibration_result(&self) -> u64 {
+        self.equations.iter().filter(|equation| equation.is_valid()).map(|equation| equation.result).sum()
+    }
+
+    pub fn calibration_result_part2(&self) -> u64 {
+        #[cfg(feature = "parallel")]
+        let equations = self.equations.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let equations = self.equations.iter();
+        equations.filter(|equation| equation.is_valid_with_concatenation()).map(|equation| equation.result).sum()
+    }
+}
+
+impl TryFrom<&str> for Equations {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let equations = parse_lines(input, Equation::parse)?;
+        Ok(Self { equations })
+    }
+}
+
+impl Solution for Equations {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        self.calibration_result().into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.calibration_result_part2().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{concat, Equations};
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../input/day-07-test");
+        let equations = Equations::try_from(input).unwrap();
+        let calibration_result = equations.calibration_result();
+        assert_eq!(calibration_result, 3749);
+    }
+    
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../input/day-07-test");
+        let equations = Equations::try_from(input).unwrap();
+        let calibration_result = equations.calibration_result_part2();
+        assert_eq!(calibration_result, 11387);
+    }
+
+    #[test]
+    fn test_overflow() {
+        // the product and the concatenation of the numbers go past u64
+        let equations = Equations::try_from("100: 99999999999 99999999999\n").unwrap();
+        assert_eq!(equations.calibration_result(), 0);
+        assert_eq!(equations.calibration_result_part2(), 0);
+        let equations = Equations::try_from("18446744073709551615: 1844674407370955161 5\n").unwrap();
+        assert_eq!(equations.calibration_result_part2(), u64::MAX);
+    }
+
+    #[test]
+    fn test_concat() {
+        assert_eq!(concat(12, 345), Some(12345));
+        assert_eq!(concat(1, 0), Some(10));
+        assert_eq!(concat(1844674407370955161, 6), None);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let error = Equations::try_from("190: 10 19\n3267 81 40 27\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 2, col 5: expected `: `");
+        let error = Equations::try_from("190: 10 19\n83: 17\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 2, col 7: expected ` `");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,150 @@
+use common::{parse::parse_grid, Answer, ParseError, Solution};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+struct Position {
+    x: i32,
+    y: i32,
+}
+
+struct Antenna {
+    position: Position,
+    frequency: char,
+}
+
+pub struct AntennaMap {
+    width: i32,
+    height: i32,
+    antennas: Vec<Antenna>
+}
+
+impl AntennaMap {
+    pub fn count_antinodes(&self) -> usize {
+        let mut range: Vec<char> = ('0'..='9').collect();
+        range.extend('a'..='z');
+        range.extend('A'..='Z');
+
+        let mut antinodes = range.iter().flat_map(|c| self.antinodes_for_frequency(*c)).filter(|antinode| self.is_valid_antinode(antinode)).collect::<Vec<_>>();
+        antinodes.sort();
+        antinodes.dedup();
+        antinodes.len()
+    }
+
+    fn antinodes_for_frequency(&self, frequency: char) -> Vec<Position> {
+        let antennas = self.antennas.iter().filter(|antenna| antenna.frequency == frequency).collect::<Vec<&Antenna>>();
+        let mut antinodes = vec![];
+        for a in 0..antennas.len() {
+            for b in (a+1)..antennas.len() {
+                let position_a = antennas[a].position;
+                let position_b = antennas[b].position;
+                let diff_x = position_a.x - position_b.x;
+                let diff_y = position_a.y - position_b.y;
+
+                let antinode_a = Position{x: position_a.x + diff_x, y: position_a.y + diff_y};
+                antinodes.push(antinode_a);
+                let antinode_b = Position{x: position_b.x - diff_x, y: position_b.y - diff_y};
+                antinodes.push(antinode_b);
+
+            }
+        }
+        antinodes
+    }
+
+    pub fn count_antinodes_with_harmonics(&self) -> usize {
+        let mut range: Vec<char> = ('0'..='9').collect();
+        range.extend('a'..='z');
+        range.extend('A'..='Z');
+
+        let mut antinodes = range.iter().flat_map(|c| self.antinodes_for_frequency_with_harmonics(*c)).collect::<Vec<_>>();
+        antinodes.sort();
+        antinodes.dedup();
+        antinodes.len()
+    }
+
+    fn antinodes_for_frequency_with_harmonics(&self, frequency: char) -> Vec<Position> {
+        let antennas = self.antennas.iter().filter(|antenna| antenna.frequency == frequency).collect::<Vec<&Antenna>>();
+        let mut antinodes = vec![];
+        for a in 0..antennas.len() {
+            for b in (a+1)..antennas.len() {
+                let position_a = antennas[a].position;
+                let position_b = antennas[b].position;
+                let diff_x = position_a.x - position_b.x;
+                let diff_y = position_a.y - position_b.y;
+
+                let mut antinode_a = position_a;
+                while self.is_valid_antinode(&antinode_a) {
+                    antinodes.push(antinode_a);
+                    antinode_a = Position{x: antinode_a.x + diff_x, y: antinode_a.y + diff_y};
+                }
+
+                let mut antinode_b = position_b;
+                while self.is_valid_antinode(&antinode_b) {
+                    antinodes.push(antinode_b);
+                    antinode_b = Position{x: antinode_b.x - diff_x, y: antinode_b.y - diff_y};
+                }
+            }
+        }
+        antinodes
+    }
+
+    fn is_valid_antinode(&self, position: &Position) -> bool {
+        position.x >= 0 && position.x < self.width && position.y >= 0 && position.y < self.height
+    }
+}
+
+impl TryFrom<&str> for AntennaMap {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let grid = parse_grid(input, |c| match c == '.' || c.is_ascii_alphanumeric() {
+            true => Ok(c),
+            false => Err(ParseError::new(format!("unexpected `{c}`, expected `.` or an antenna frequency"))),
+        })?;
+        let height = grid.len() as i32;
+        let width = grid[0].len() as i32;
+        let cells = grid.iter().enumerate().flat_map(|(y, line)| {
+            line.iter().enumerate().filter(|(_, c)| c != &&'.').map(|(x, c)| Antenna{position:Position{x: x as i32, y: y as i32}, frequency: *c}).collect::<Vec<Antenna>>()
+        }).collect();
+        Ok(Self{ height, width, antennas: cells })
+    }
+}
+
+impl Solution for AntennaMap {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        self.count_antinodes().into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.count_antinodes_with_harmonics().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::AntennaMap;
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../input/day-08-test");
+        let antenna_map = AntennaMap::try_from(input).unwrap();
+        let count = antenna_map.count_antinodes();
+        assert_eq!(count, 14);
+    }
+    
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../input/day-08-test");
+        let antenna_map = AntennaMap::try_from(input).unwrap();
+        let count = antenna_map.count_antinodes_with_harmonics();
+        assert_eq!(count, 34);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let error = AntennaMap::try_from("..a.\n.#..\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 2, col 2: unexpected `#`, expected `.` or an antenna frequency");
+    }
+}
\ No newline at end of file
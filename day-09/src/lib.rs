@@ -0,0 +1,243 @@
+use common::{parse::lines, Answer, ParseError, Solution};
+
+#[derive(Debug,Clone, Copy, PartialEq)]
+enum State {
+    Empty,
+    Full(u64),
+}
+
+impl State {
+    fn unwrap_or_zero(&self) -> u64 {
+        match self {
+            State::Empty => 0,
+            State::Full(value) => *value,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        match self {
+            State::Empty => true,
+            State::Full(_) => false,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct FileSystem {
+    layout: Vec<State>
+}
+
+impl FileSystem {
+    pub fn condense(&self) -> Self {
+        let mut layout = vec![];
+        let mut rev_full_iter = self.layout.iter().enumerate().filter_map(|(index, &cell)| match cell {
+            State::Empty => None,
+            State::Full(value) => Some((index, value)),
+        }).rev();
+        let mut current_end_index = self.layout.len();
+        for (index, cell) in self.layout.iter().enumerate() {
+            match cell {
+                State::Empty => {
+                    let (index_from_end, value_from_end) = rev_full_iter.next().unwrap();
+                    current_end_index = index_from_end;
+                    if index > current_end_index {
+                        break;
+                    } else {
+                        layout.push(State::Full(value_from_end));
+                        current_end_index -= 1;
+                    }
+                },
+                State::Full(value) => {
+                    if index > current_end_index {
+                        break;
+                    } else {
+                        layout.push(State::Full(*value))
+                    }
+                },
+            }
+        }
+        Self { layout }
+    }
+
+    pub fn condense_without_fragmentation(&self) -> Self {
+        let mut file_location = self.layout.len() - 1;
+        let mut layout = self.layout.clone();
+        while file_location > 1 {
+            let file_size;
+            (file_location, file_size) = find_next_file(&layout, file_location);
+            if let Some(new_location) = find_better_location(&layout, file_location, file_size) {
+                for offset in 0..file_size {
+                    layout[new_location + offset] = layout[file_location + offset];
+                    layout[file_location + offset] = State::Empty;
+                }
+            }
+            // the first file has nowhere to go
+            if file_location == 0 {
+                break;
+            }
+            file_location -= 1;
+        }
+        Self { layout }
+    }
+
+    pub fn compute_checksum(&self) -> u64 {
+        self.layout.iter().enumerate().map(|(index, value)| index as u64 * value.unwrap_or_zero()).sum()
+    }
+}
+
+fn find_next_file(layout: &[State], current_location: usize) -> (usize, usize) {
+    let mut location = current_location;
+    while layout[location].is_empty() {
+        // no risk of underflow since the file system always starts with a file
+        location -= 1;
+    }
+    let current_file = layout[location];
+    let end_location = location;
+    while location > 0 && layout[location - 1] == current_file {
+        location -= 1;
+    }
+    (location, end_location - location + 1)
+}
+
+fn find_better_location(layout: &[State], file_location: usize, file_size: usize) -> Option<usize> {
+    let mut next = next_empty_location(layout, 0);
+    while let Some((location, size)) = next {
+        if location >= file_location {
+            return None;
+        }
+        if size >= file_size {
+            return Some(location);
+        }
+        next = next_empty_location(layout, location + 1);
+    }
+    None
+}
+
+// the first free span from `current_location` on and its size, `None` past the last one
+fn next_empty_location(layout: &[State], current_location: usize) -> Option<(usize, usize)> {
+    let start_location = current_location + layout[current_location..].iter().position(|state| state.is_empty())?;
+    let size = layout[start_location..].iter().take_while(|state| state.is_empty()).count();
+    Some((start_location, size))
+}
+
+impl TryFrom<&str> for FileSystem {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut lines = lines(input);
+        let mut cursor = lines.next().ok_or_else(|| ParseError::end_of_input(input, "expected a disk map"))?;
+        let mut index = 0;
+        let mut full = true;
+        let mut layout = vec![];
+        loop {
+            let size = match cursor.peek().and_then(|c| c.to_digit(10)) {
+                Some(size) => size as usize,
+                None if cursor.column() > 1 => break,
+                None => return Err(cursor.error("expected digit")),
+            };
+            let space = if full { let state = State::Full(index); index += 1; state } else { State::Empty };
+            full = !full;
+            layout.extend(vec![space; size]);
+            cursor.next_char();
+        }
+        cursor.finish()?;
+        lines.try_for_each(|cursor| cursor.finish())?;
+        if layout.is_empty() {
+            return Err(ParseError::at(1, 1, "the disk map has no blocks"));
+        }
+        Ok(Self { layout })
+    }
+}
+
+impl Solution for FileSystem {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        self.condense().compute_checksum().into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.condense_without_fragmentation().compute_checksum().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use crate::{FileSystem, State};
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../input/day-09-test");
+        let sparse_file_system = FileSystem::try_from(input).unwrap();
+        let condensed_file_system = sparse_file_system.condense();
+        let count = condensed_file_system.compute_checksum();
+        assert_eq!(count, 1928);
+    }
+    
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../input/day-09-test");
+        let sparse_file_system = FileSystem::try_from(input).unwrap();
+        let condensed_file_system = sparse_file_system.condense_without_fragmentation();
+        let count = condensed_file_system.compute_checksum();
+        assert_eq!(count, 2858);
+    }
+
+    #[test]
+    fn test_part2_long_first_file() {
+        let file_system = FileSystem::try_from("8123").unwrap();
+        assert_eq!(file_system.condense_without_fragmentation().compute_checksum(), 19);
+    }
+
+    #[test]
+    fn test_part2_no_free_space() {
+        // the second file doesn't fit in the gap and nothing is free after it
+        let file_system = FileSystem::try_from("168").unwrap();
+        assert_eq!(file_system.condense_without_fragmentation().compute_checksum(), (7..15).sum::<u64>());
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(FileSystem::try_from("12345\n").is_ok());
+        let error = FileSystem::try_from("123a5").err().unwrap();
+        assert_eq!(error.to_string(), "line 1, col 4: unexpected `a`");
+        let error = FileSystem::try_from("12345\n678\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 2, col 1: unexpected `6`");
+        let error = FileSystem::try_from("").err().unwrap();
+        assert_eq!(error.to_string(), "line 1, col 1: unexpected end of input, expected a disk map");
+        let error = FileSystem::try_from("000").err().unwrap();
+        assert_eq!(error.to_string(), "line 1, col 1: the disk map has no blocks");
+    }
+
+    // files are never empty, so each run of blocks is a file or the free space after it
+    fn to_input(file_system: &FileSystem) -> String {
+        let runs = file_system.layout.chunk_by(|a, b| a == b);
+        let mut disk_map = String::new();
+        runs.for_each(|run| {
+            if !run[0].is_empty() && disk_map.len() % 2 == 1 {
+                disk_map.push('0');
+            }
+            disk_map.push_str(&run.len().to_string());
+        });
+        disk_map
+    }
+
+    fn file_system() -> impl Strategy<Value = FileSystem> {
+        prop::collection::vec((1..=9usize, 0..=9usize), 1..20).prop_map(|files| {
+            let layout = files.into_iter().enumerate().flat_map(|(id, (size, free))| {
+                [vec![State::Full(id as u64); size], vec![State::Empty; free]].concat()
+            }).collect();
+            FileSystem { layout }
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(file_system in file_system()) {
+            prop_assert_eq!(FileSystem::try_from(to_input(&file_system).as_str()), Ok(file_system));
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,89 @@
+use common::{Answer, ParseError, Solution};
+use grid::{Connectivity, Grid};
+
+pub struct TopographicMap {
+    map: Grid<u8>
+}
+
+impl TopographicMap {
+    pub fn count_trailheads_scores(&self) -> (usize, usize) {
+        // ids of the summits reachable from each cell, going down from the summits
+        let mut map_with_count: Grid<Vec<usize>> = Grid::new(self.map.width(), self.map.height(), vec![]);
+        let mut id = 0;
+        for altitude in (0u8..=9u8).rev() {
+            for position in self.map.positions().filter(|&position| self.map[position] == altitude) {
+                if altitude == 9 {
+                    map_with_count[position].push(id);
+                    id += 1;
+                } else {
+                    let reachable = self.map.neighbors(position, Connectivity::Four)
+                        .filter(|&neighbor| self.map[neighbor] == altitude + 1)
+                        .flat_map(|neighbor| map_with_count[neighbor].clone())
+                        .collect();
+                    map_with_count[position] = reachable;
+                }
+            }
+        }
+
+        let trailheads: Vec<_> = self.map.positions().filter(|&position| self.map[position] == 0).collect();
+        let part_2 = trailheads.iter().map(|&position| map_with_count[position].len()).sum();
+        let part_1 = trailheads.iter().map(|&position| {
+            map_with_count[position].sort();
+            map_with_count[position].dedup();
+            map_with_count[position].len()
+        }).sum();
+        (part_1, part_2)
+    }
+}
+
+impl TryFrom<&str> for TopographicMap {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let map = Grid::parse(input, |c| c.to_digit(10).map(|height| height as u8).ok_or_else(|| ParseError::new("expected digit")))?;
+        Ok(Self { map })
+    }
+}
+
+impl Solution for TopographicMap {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        let (score, _) = self.count_trailheads_scores();
+        score.into()
+    }
+
+    fn part2(&self) -> Answer {
+        let (_, rating) = self.count_trailheads_scores();
+        rating.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TopographicMap;
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../input/day-10-test");
+        let topographic_map = TopographicMap::try_from(input).unwrap();
+        let (count, _) = topographic_map.count_trailheads_scores();
+        assert_eq!(count, 36);
+    }
+    
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../input/day-10-test");
+        let topographic_map = TopographicMap::try_from(input).unwrap();
+        let (_, count) = topographic_map.count_trailheads_scores();
+        assert_eq!(count, 81);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let error = TopographicMap::try_from("0123\n1234\n87.5\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 3, col 3: expected digit");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,149 @@
+use common::{Answer, ParseError, Solution};
+use grid::{Connectivity, Grid, Position};
+
+pub struct Garden {
+    plants: Grid<char>,
+}
+
+impl Garden {
+    pub fn compute_regular_fencing_price(&self) -> u32 {
+        self.compute_fencing_price(false)
+    }
+
+    pub fn compute_fencing_price_with_discount(&self) -> u32 {
+        self.compute_fencing_price(true)
+    }
+
+    fn compute_fencing_price(&self, discount: bool) -> u32 {
+        let mut price = 0;
+        let mut non_visited = Grid::new(self.plants.width(), self.plants.height(), true);
+        for position in self.plants.positions() {
+            if non_visited[position] {
+                price += self.compute_fence_for_area(position, &mut non_visited, discount);
+            }
+        }
+        price
+    }
+
+    fn compute_fence_for_area(&self, start: Position, non_visited: &mut Grid<bool>, discount: bool) -> u32 {
+        let mut area = 0;
+        let mut perimeter = 0;
+        let mut plants_to_count = vec![start];
+        while let Some(position) = plants_to_count.pop() {
+            if non_visited[position] {
+                area += 1;
+                let (local_perimeter, mut plants) = self.analyze_plant(position, non_visited, discount);
+                perimeter += local_perimeter;
+                plants_to_count.append(&mut plants);
+                non_visited[position] = false;
+            }
+        }
+        area * perimeter
+    }
+
+    fn analyze_plant(&self, position: Position, non_visited: &Grid<bool>, discount: bool) -> (u32, Vec<Position>) {
+        let plant_type = self.plants[position];
+        let same_region: Vec<Position> = self.plants.neighbors(position, Connectivity::Four)
+            .filter(|&neighbor| self.plants[neighbor] == plant_type)
+            .collect();
+        let neighbors = same_region.iter().copied().filter(|&neighbor| non_visited[neighbor]).collect();
+
+        // with the discount, a region has as many sides as corners
+        let perimeter = if discount {
+            self.count_corners(position)
+        } else {
+            4 - same_region.len() as u32
+        };
+        (perimeter, neighbors)
+    }
+
+    fn plant_at(&self, position: Position, dx: isize, dy: isize) -> Option<char> {
+        position.offset(dx, dy).and_then(|neighbor| self.plants.get(neighbor)).copied()
+    }
+
+    fn count_corners(&self, position: Position) -> u32 {
+        let plant_type = Some(self.plants[position]);
+        [(-1, -1), (1, -1), (-1, 1), (1, 1)].into_iter().filter(|&(dx, dy)| {
+            let horizontal = self.plant_at(position, dx, 0);
+            let vertical = self.plant_at(position, 0, dy);
+            let diagonal = self.plant_at(position, dx, dy);
+            let is_outward_corner = horizontal != plant_type && vertical != plant_type;
+            let is_inward_corner = horizontal == plant_type && vertical == plant_type && diagonal != plant_type;
+            is_outward_corner || is_inward_corner
+        }).count() as u32
+    }
+}
+
+impl TryFrom<&str> for Garden {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let plants = Grid::parse(input, Ok)?;
+        Ok(Self { plants })
+    }
+}
+
+impl Solution for Garden {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        self.compute_regular_fencing_price().into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.compute_fencing_price_with_discount().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Garden;
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../input/day-12-test");
+        let garden = Garden::try_from(input).unwrap();
+        let price = garden.compute_regular_fencing_price();
+        assert_eq!(price, 1930);
+    }
+    
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../input/day-12-test");
+        let garden = Garden::try_from(input).unwrap();
+        let price = garden.compute_fencing_price_with_discount();
+        assert_eq!(price, 1206);
+    }
+
+    #[test]
+    fn test_part2_2() {
+        let input = include_str!("../../input/day-12-test-2");
+        let garden = Garden::try_from(input).unwrap();
+        let price = garden.compute_fencing_price_with_discount();
+        assert_eq!(price, 436);
+    }
+
+    #[test]
+    fn test_part2_3() {
+        let input = include_str!("../../input/day-12-test-3");
+        let garden = Garden::try_from(input).unwrap();
+        let price = garden.compute_fencing_price_with_discount();
+        assert_eq!(price, 236);
+    }
+
+    #[test]
+    fn test_part2_4() {
+        let input = include_str!("../../input/day-12-test-4");
+        let garden = Garden::try_from(input).unwrap();
+        let price = garden.compute_fencing_price_with_discount();
+        assert_eq!(price, 368);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let error = Garden::try_from("AAAA\nBBCD\nBBC\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 3, col 4: expected 4 cells on this row, found 3");
+    }
+}
\ No newline at end of file
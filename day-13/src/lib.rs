@@ -0,0 +1,179 @@
+use common::{parse::{lines, Cursor}, Answer, ParseError, Solution};
+
+const MAX_BUTTON_PRESSES: i64 = 100;
+const PART_2_OFFSET: i64 = 10_000_000_000_000;
+
+#[derive(Debug, PartialEq)]
+pub struct Machines {
+    machines: Vec<Machine>,
+}
+
+impl Machines {
+    pub fn count_tokens_bruteforce(&self) -> i64 {
+        self.machines.iter().filter_map(|machine| machine.count_tokens_bruteforce()).sum()
+    }
+
+    pub fn count_tokens(&self) -> i64 {
+        self.machines.iter().filter_map(|machine| machine.count_tokens()).sum()
+    }
+
+    pub fn add_offset(&self) -> Self {
+        let machines = self.machines.iter().map(|machine| machine.add_offset()).collect();
+        Self { machines }
+    }
+}
+
+impl TryFrom<&str> for Machines {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut machines = vec![];
+
+        let mut lines = lines(input);
+        while let Some(mut line_a) = lines.next() {
+            let (a_x, a_y) = parse_coordinates(&mut line_a, "Button A: X+", ", Y+")?;
+
+            let mut line_b = lines.next().ok_or_else(|| ParseError::end_of_input(input, "expected `Button B: X+`"))?;
+            let (b_x, b_y) = parse_coordinates(&mut line_b, "Button B: X+", ", Y+")?;
+
+            let mut line_prize = lines.next().ok_or_else(|| ParseError::end_of_input(input, "expected `Prize: X=`"))?;
+            let (prize_x, prize_y) = parse_coordinates(&mut line_prize, "Prize: X=", ", Y=")?;
+
+            machines.push(Machine { a_x, a_y, b_x, b_y, prize_x, prize_y });
+            if let Some(separator) = lines.next() {
+                separator.finish()?;
+            }
+        }
+
+        Ok(Self { machines })
+    }
+}
+
+fn parse_coordinates(cursor: &mut Cursor, x_prefix: &str, y_prefix: &str) -> Result<(i64, i64), ParseError> {
+    cursor.expect(x_prefix)?;
+    let x = cursor.number::<i64>()?;
+    cursor.expect(y_prefix)?;
+    let y = cursor.number::<i64>()?;
+    cursor.finish()?;
+    Ok((x, y))
+}
+
+#[derive(Debug, PartialEq)]
+struct Machine {
+    a_x: i64,
+    a_y: i64,
+    b_x: i64,
+    b_y: i64,
+    prize_x: i64,
+    prize_y: i64,
+}
+
+impl Machine {
+    pub fn count_tokens_bruteforce(&self) -> Option<i64> {
+        // worst case is 100 times button A -> 300 tokens and 100 times button B, +1 to make it infeasible
+        let upper_bound = 401;
+        let mut minimum_tokens = upper_bound;
+        for a_presses in 0..MAX_BUTTON_PRESSES {
+            for b_presses in 0..MAX_BUTTON_PRESSES {
+                let x_position = a_presses * self.a_x + b_presses * self.b_x;
+                let y_position = a_presses * self.a_y + b_presses * self.b_y;
+                let tokens = a_presses * 3 + b_presses;
+                if x_position == self.prize_x && y_position == self.prize_y && tokens < minimum_tokens {
+                    minimum_tokens = tokens;
+                }
+            }
+        }
+        if minimum_tokens >= upper_bound {
+            None
+        } else {
+            Some(minimum_tokens)
+        }
+    }
+
+    pub fn count_tokens(&self) -> Option<i64> {
+        // we're actually solving a system of 2 equations with 2 unknowns
+        let determinant = self.a_x * self.b_y - self.b_x * self.a_y;
+        if determinant == 0 {
+            None
+        } else {
+            let determinant_1 = self.prize_x * self.b_y - self.b_x * self.prize_y;
+            let determinant_2 = self.a_x * self.prize_y - self.prize_x * self.a_y;
+            let a = determinant_1 / determinant;
+            let b = determinant_2 / determinant;
+
+            if self.a_x * a + self.b_x * b == self.prize_x && self.a_y * a + self.b_y * b == self.prize_y {
+                Some(3 * a + b)
+            } else {
+                None
+            }
+        }
+    }
+
+    pub fn add_offset(&self) -> Self {
+        Self { a_x: self.a_x, a_y: self.a_y, b_x: self.b_x, b_y: self.b_y, prize_x: self.prize_x + PART_2_OFFSET, prize_y: self.prize_y + PART_2_OFFSET }
+    }
+}
+
+impl Solution for Machines {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        self.count_tokens_bruteforce().into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.add_offset().count_tokens().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{Answer, Solution};
+    use proptest::prelude::*;
+    use crate::{Machine, Machines};
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../input/day-13-test");
+        let machines = Machines::try_from(input).unwrap();
+        let tokens = machines.count_tokens_bruteforce();
+        assert_eq!(tokens, 480);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../input/day-13-test");
+        let machines = Machines::parse(input).unwrap();
+        assert_eq!(machines.part2(), Answer::Number(875318608908));
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let error = Machines::try_from("Button A: X+94, Y+34\nButton B: X+22, Y=67\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, col 15: expected `, Y+`");
+        let error = Machines::try_from("Button A: X+94, Y+34\nButton B: X+22, Y+67\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 3, col 1: unexpected end of input, expected `Prize: X=`");
+    }
+
+    fn to_input(machines: &Machines) -> String {
+        machines.machines.iter().map(|machine| format!(
+            "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+            machine.a_x, machine.a_y, machine.b_x, machine.b_y, machine.prize_x, machine.prize_y,
+        )).collect::<Vec<String>>().join("\n")
+    }
+
+    fn machine() -> impl Strategy<Value = Machine> {
+        (0..100i64, 0..100i64, 0..100i64, 0..100i64, 0..100_000i64, 0..100_000i64)
+            .prop_map(|(a_x, a_y, b_x, b_y, prize_x, prize_y)| Machine { a_x, a_y, b_x, b_y, prize_x, prize_y })
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(machines in prop::collection::vec(machine(), 0..10)) {
+            let machines = Machines { machines };
+            prop_assert_eq!(Machines::try_from(to_input(&machines).as_str()), Ok(machines));
+        }
+    }
+}
\ No newline at end of file
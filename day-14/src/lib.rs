@@ -0,0 +1,231 @@
+use std::path::{Path, PathBuf};
+use common::{parse::{parse_lines, Cursor}, Answer, ParseError, Solution};
+
+#[derive(Debug, PartialEq)]
+struct Robot {
+    x: i32,
+    y: i32,
+    vx: i32,
+    vy: i32,
+}
+
+impl Robot {
+    fn compute_position_after_n_seconds(&self, seconds: i32, width: i32, height: i32) -> (i32, i32) {
+        let new_x = (self.x + self.vx * seconds).rem_euclid(width);
+        let new_y = (self.y + self.vy * seconds).rem_euclid(height);
+        (new_x, new_y)
+    }
+}
+
+impl Robot {
+    fn parse(cursor: &mut Cursor) -> Result<Self, ParseError> {
+        cursor.expect("p=")?;
+        let x = parse_position(cursor)?;
+        cursor.expect(",")?;
+        let y = parse_position(cursor)?;
+        cursor.expect(" v=")?;
+        let vx = cursor.number::<i32>()?;
+        cursor.expect(",")?;
+        let vy = cursor.number::<i32>()?;
+        Ok(Self { x, y, vx, vy })
+    }
+}
+
+// the room size is deduced from the positions, so they can't be negative
+fn parse_position(cursor: &mut Cursor) -> Result<i32, ParseError> {
+    let column = cursor.column();
+    let position = cursor.number::<i32>()?;
+    if position < 0 {
+        return Err(ParseError::at(cursor.line(), column, "expected a position inside the room"));
+    }
+    Ok(position)
+}
+
+impl TryFrom<&str> for Robot {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut cursor = Cursor::new(1, input);
+        let robot = Robot::parse(&mut cursor)?;
+        cursor.finish()?;
+        Ok(robot)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Robots {
+    robots: Vec<Robot>,
+    width: i32,
+    height: i32,
+}
+
+impl Robots {
+    pub fn compute_safety_factor(&self) -> u32 {
+        let h_middle = self.height / 2;
+        let v_middle = self.width / 2;
+        
+        let robots_after_100_seconds: Vec<(i32, i32)> = self.robots.iter().map(|robot|
+            robot.compute_position_after_n_seconds(100, self.width, self.height)
+        ).collect();
+
+        let mut in_ne_quadrant = 0;
+        let mut in_nw_quadrant = 0;
+        let mut in_se_quadrant = 0;
+        let mut in_sw_quadrant = 0;
+        robots_after_100_seconds.into_iter().for_each(|(x, y)| {
+            if x < v_middle && y < h_middle {
+                in_nw_quadrant += 1;
+            } else if x > v_middle && y < h_middle {
+                in_ne_quadrant += 1;
+            } else if x < v_middle && y > h_middle {
+                in_sw_quadrant += 1;
+            } else if x > v_middle && y > h_middle {
+                in_se_quadrant += 1;
+            }
+        });
+
+        in_ne_quadrant * in_nw_quadrant * in_se_quadrant * in_sw_quadrant
+    }
+
+    pub fn get_positions_after_n_seconds(&self, n: i32) -> Vec<(i32, i32)> {
+        self.robots.iter().map(|robot| {
+            robot.compute_position_after_n_seconds(n, self.width, self.height)
+        }).collect()
+    }
+    
+    pub fn find_christmas_tree(&self) -> Option<i32> {
+        // the picture shows up the first time no two robots share a tile
+        (0..self.width * self.height).find(|&n| {
+            let mut positions = self.get_positions_after_n_seconds(n);
+            let count = positions.len();
+            positions.sort();
+            positions.dedup();
+            positions.len() == count
+        })
+    }
+
+    #[allow(dead_code)]
+    fn display(&self, positions: Vec<(i32, i32)>) {
+        for line_index in 0..self.height {
+            let mut line = "".to_string();
+            for col_index in 0..self.width {
+                if positions.contains(&(col_index, line_index)) {
+                    line.push('#');
+                } else {
+                    line.push('.');
+                }
+            }
+            println!("{line}");
+        }
+        println!();
+    }
+
+    /// Writes the robots as green pixels to `<dir>/<index>.png`, returning the path.
+    pub fn save_image(&self, positions: Vec<(i32, i32)>, index: i32, dir: &Path) -> Result<PathBuf, String> {
+        let mut imgbuf = image::ImageBuffer::new(self.width as u32, self.height as u32);
+        for (x, y, pixel) in imgbuf.enumerate_pixels_mut() {
+            if positions.contains(&(x as i32, y as i32)) {
+                *pixel = image::Rgb([0, 255u8, 0]);
+            }
+        }
+        let path = dir.join(format!("{index}.png"));
+        imgbuf.save(&path).map_err(|error| format!("cannot write {}: {error}", path.display()))?;
+        Ok(path)
+    }
+}
+
+impl TryFrom<&str> for Robots {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let robots: Vec<Robot> = parse_lines(input, Robot::parse)?;
+        let width = robots.iter().map(|robot| robot.x).max().ok_or_else(|| ParseError::end_of_input(input, "expected a robot"))? + 1;
+        let height = robots.iter().map(|robot| robot.y).max().unwrap_or_default() + 1;
+        Ok(Self { robots, width, height })
+    }
+}
+
+impl Solution for Robots {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        self.compute_safety_factor().into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.find_christmas_tree().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+    use proptest::prelude::*;
+    use crate::{Robot, Robots};
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../input/day-14-test");
+        let robots = Robots::try_from(input).unwrap();
+        let safety_factor = robots.compute_safety_factor();
+        assert_eq!(safety_factor, 12);
+    }
+
+    #[test]
+    fn test_part1_robot_move() {
+        let robot = Robot { x: 2, y: 4, vx: 2, vy: -3 };
+        let position = robot.compute_position_after_n_seconds(1, 11, 7);
+        assert_eq!(position, (4, 1));
+        let position = robot.compute_position_after_n_seconds(2, 11, 7);
+        assert_eq!(position, (6, 5));
+        let position = robot.compute_position_after_n_seconds(3, 11, 7);
+        assert_eq!(position, (8, 2));
+        let position = robot.compute_position_after_n_seconds(4, 11, 7);
+        assert_eq!(position, (10, 6));
+        let position = robot.compute_position_after_n_seconds(5, 11, 7);
+        assert_eq!(position, (1, 3));
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let error = Robots::try_from("p=0,4 v=3,-3\np=6,3 v=-1,x\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 2, col 12: expected digit");
+        let error = Robot::try_from("p=-2,4 v=3,-3").err().unwrap();
+        assert_eq!(error.to_string(), "line 1, col 3: expected a position inside the room");
+        let error = Robots::try_from("").err().unwrap();
+        assert_eq!(error.to_string(), "line 1, col 1: unexpected end of input, expected a robot");
+    }
+
+    #[test]
+    fn test_save_image() {
+        let robots = Robots::try_from(include_str!("../../input/day-14-test")).unwrap();
+        let dir = env::temp_dir().join(format!("day-14-images-{}", process::id()));
+        let error = robots.save_image(vec![(0, 0)], 3, &dir).unwrap_err();
+        assert!(error.starts_with(&format!("cannot write {}", dir.join("3.png").display())), "{error}");
+        fs::create_dir_all(&dir).unwrap();
+        assert_eq!(robots.save_image(vec![(0, 0)], 3, &dir), Ok(dir.join("3.png")));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    fn to_input(robots: &Robots) -> String {
+        robots.robots.iter().map(|robot| format!("p={},{} v={},{}\n", robot.x, robot.y, robot.vx, robot.vy)).collect()
+    }
+
+    fn robots() -> impl Strategy<Value = Robots> {
+        let robot = (0..101, 0..103, -100..100, -100..100).prop_map(|(x, y, vx, vy)| Robot { x, y, vx, vy });
+        prop::collection::vec(robot, 1..20).prop_map(|robots| {
+            let width = robots.iter().map(|robot| robot.x).max().unwrap() + 1;
+            let height = robots.iter().map(|robot| robot.y).max().unwrap() + 1;
+            Robots { robots, width, height }
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(robots in robots()) {
+            prop_assert_eq!(Robots::try_from(to_input(&robots).as_str()), Ok(robots));
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,473 @@
+use common::{parse::lines, Answer, ParseError, Solution};
+use grid::{Direction, Grid, Position};
+
+#[derive(PartialEq, Clone)]
+enum Cell {
+    Wall,
+    Box,
+    Empty,
+    Robot,
+}
+
+impl TryFrom<char> for Cell {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '#' => Ok(Self::Wall),
+            'O' => Ok(Self::Box),
+            '.' => Ok(Self::Empty),
+            '@' => Ok(Self::Robot),
+            _ => Err(ParseError::new(format!("unexpected `{c}`, expected `#`, `O`, `.` or `@`"))),
+        }
+    }
+}
+
+impl Cell {
+    pub fn scale_up(&self) -> [BigCell;2] {
+        match self {
+            Cell::Wall => [BigCell::Wall, BigCell::Wall],
+            Cell::Box => [BigCell::BoxL, BigCell::BoxR],
+            Cell::Empty => [BigCell::Empty, BigCell::Empty],
+            Cell::Robot => [BigCell::Robot, BigCell::Empty],
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum BigCell {
+    Wall,
+    BoxL,
+    BoxR,
+    Empty,
+    Robot,
+}
+
+impl From<BigCell> for char {
+    fn from(cell: BigCell) -> Self {
+        match cell {
+            BigCell::Wall => '#',
+            BigCell::BoxL => '[',
+            BigCell::BoxR => ']',
+            BigCell::Empty => '.',
+            BigCell::Robot => '@',
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Warehouse {
+    cells: Grid<Cell>,
+    robot: Position,
+    instructions: Vec<Direction>
+}
+
+impl Warehouse {
+    pub fn follow_instructions(&mut self) {
+        let instructions = self.instructions.clone();
+        instructions.into_iter().for_each(|instruction| self.follow_instruction(instruction));
+    }
+
+    fn follow_instruction(&mut self, instruction: Direction) {
+        // the nice thing with having a full boundary of walls is that we don't have to check coordinates
+        let x = self.robot.x;
+        let y = self.robot.y;
+        match instruction {
+            Direction::North => {
+                match self.cells[y-1][x] {
+                    Cell::Wall => {/* nothing to do, robot is against a wall */},
+                    Cell::Box => {
+                        let mut forward_y = y-1;
+                        while self.cells[forward_y][x] == Cell::Box {
+                            forward_y -= 1;
+                        }
+                        if self.cells[forward_y][x] == Cell::Wall {
+                            /* nothing to do, boxes are stacked against a wall */
+                        } else {
+                            // there's an empty space at forward_y
+                            self.cells[forward_y][x] = Cell::Box;
+                            self.cells[y][x] = Cell::Empty;
+                            self.cells[y-1][x] = Cell::Robot;
+                            self.robot = Position{x, y: y-1};
+                        }
+                    },
+                    Cell::Empty => {
+                        self.cells[y][x] = Cell::Empty;
+                        self.cells[y-1][x] = Cell::Robot;
+                        self.robot = Position{x, y: y-1};
+                    },
+                    Cell::Robot => unreachable!(),
+                }
+            },
+            Direction::South => {
+                match self.cells[y+1][x] {
+                    Cell::Wall => {/* nothing to do, robot is against a wall */},
+                    Cell::Box => {
+                        let mut forward_y = y+1;
+                        while self.cells[forward_y][x] == Cell::Box {
+                            forward_y += 1;
+                        }
+                        if self.cells[forward_y][x] == Cell::Wall {
+                            /* nothing to do, boxes are stacked against a wall */
+                        } else {
+                            // there's an empty space at forward_y
+                            self.cells[forward_y][x] = Cell::Box;
+                            self.cells[y][x] = Cell::Empty;
+                            self.cells[y+1][x] = Cell::Robot;
+                            self.robot = Position{x, y: y+1};
+                        }
+                    },
+                    Cell::Empty => {
+                        self.cells[y][x] = Cell::Empty;
+                        self.cells[y+1][x] = Cell::Robot;
+                        self.robot = Position{x, y: y+1};
+                    },
+                    Cell::Robot => unreachable!(),
+                }
+            },
+            Direction::West => {
+                match self.cells[y][x-1] {
+                    Cell::Wall => {/* nothing to do, robot is against a wall */},
+                    Cell::Box => {
+                        let mut forward_x = x-1;
+                        while self.cells[y][forward_x] == Cell::Box {
+                            forward_x -= 1;
+                        }
+                        if self.cells[y][forward_x] == Cell::Wall {
+                            /* nothing to do, boxes are stacked against a wall */
+                        } else {
+                            // there's an empty space at forward_y
+                            self.cells[y][forward_x] = Cell::Box;
+                            self.cells[y][x] = Cell::Empty;
+                            self.cells[y][x-1] = Cell::Robot;
+                            self.robot = Position{x: x-1, y};
+                        }
+                    },
+                    Cell::Empty => {
+                        self.cells[y][x] = Cell::Empty;
+                        self.cells[y][x-1] = Cell::Robot;
+                        self.robot = Position{x: x-1, y};
+                    },
+                    Cell::Robot => unreachable!(),
+                }
+            },
+            Direction::East => {
+                match self.cells[y][x+1] {
+                    Cell::Wall => {/* nothing to do, robot is against a wall */},
+                    Cell::Box => {
+                        let mut forward_x = x+1;
+                        while self.cells[y][forward_x] == Cell::Box {
+                            forward_x += 1;
+                        }
+                        if self.cells[y][forward_x] == Cell::Wall {
+                            /* nothing to do, boxes are stacked against a wall */
+                        } else {
+                            // there's an empty space at forward_y
+                            self.cells[y][forward_x] = Cell::Box;
+                            self.cells[y][x] = Cell::Empty;
+                            self.cells[y][x+1] = Cell::Robot;
+                            self.robot = Position{x: x+1, y};
+                        }
+                    },
+                    Cell::Empty => {
+                        self.cells[y][x] = Cell::Empty;
+                        self.cells[y][x+1] = Cell::Robot;
+                        self.robot = Position{x: x+1, y};
+                    },
+                    Cell::Robot => unreachable!(),
+                }
+            },
+        }
+    }
+
+    pub fn sum_boxes_coordinates(&self) -> usize {
+        self.cells.iter().filter(|(_, cell)| **cell == Cell::Box).map(|(position, _)| 100*position.y + position.x).sum()
+    }
+
+    pub fn scale_up(&self) -> BigWarehouse {
+        let instructions = self.instructions.clone();
+        let cells = Grid::from_rows(self.cells.rows()
+            .map(|line|
+                line.iter().flat_map(|cell| cell.scale_up()).collect()
+            ).collect());
+        let robot = Position{x: self.robot.x * 2, y: self.robot.y};
+
+        BigWarehouse { cells, robot, instructions }
+    }
+}
+
+impl TryFrom<&str> for Warehouse {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let map = input.split("\n\n").next().unwrap_or_default();
+        let cells = Grid::parse(map, Cell::try_from)?;
+        // the robot moves around without bound checks
+        cells.check_walled(|cell| *cell == Cell::Wall)?;
+        let robot = cells.find_unique(|cell| *cell == Cell::Robot, "robot `@`")?;
+
+        let mut instructions = vec![];
+        for mut cursor in lines(input).skip(cells.height() + 1) {
+            while let Some(c) = cursor.peek() {
+                instructions.push(Direction::try_from(c).map_err(|error| error.located(cursor.line(), cursor.column()))?);
+                cursor.next_char();
+            }
+        }
+
+        Ok(Self { cells, robot, instructions })
+    }
+}
+
+pub struct BigWarehouse {
+    cells: Grid<BigCell>,
+    robot: Position,
+    instructions: Vec<Direction>
+}
+
+impl BigWarehouse {
+    pub fn follow_instructions(&mut self) {
+        let instructions = self.instructions.clone();
+        instructions.into_iter().for_each(|instruction| {
+            self.follow_instruction(instruction);
+            //self.draw();
+        });
+    }
+
+    #[allow(dead_code)]
+    fn draw(&self) {
+        println!("Robot in {}, {}", self.robot.x, self.robot.y);
+        println!("{}", self.cells.render(|&cell| cell.into()));
+    }
+
+    fn follow_instruction(&mut self, instruction: Direction) {
+        // the nice thing with having a full boundary of walls is that we don't have to check coordinates
+        let x = self.robot.x;
+        let y = self.robot.y;
+        match instruction {
+            Direction::North => {
+                match self.cells[y-1][x] {
+                    BigCell::Wall => { /* nothing to do */},
+                    BigCell::BoxL => {
+                        if self.can_push_box_up_down(x, y-1, true) {
+                            self.push_box_up_down(x, y-1, true);
+                            self.move_robot(x, y-1);
+                        }
+                    },
+                    BigCell::BoxR => {
+                        if self.can_push_box_up_down(x-1, y-1, true) {
+                            self.push_box_up_down(x-1, y-1, true);
+                            self.move_robot(x, y-1);
+                        }
+                    }
+                    BigCell::Empty => {
+                        self.move_robot(x, y-1);
+                    },
+                    BigCell::Robot => unreachable!(),
+                }
+            },
+            Direction::South => {
+                match self.cells[y+1][x] {
+                    BigCell::Wall => { /* nothing to do */},
+                    BigCell::BoxL => {
+                        if self.can_push_box_up_down(x, y+1, false) {
+                            self.push_box_up_down(x, y+1, false);
+                            self.move_robot(x, y+1);
+                        }
+                    },
+                    BigCell::BoxR => {
+                        if self.can_push_box_up_down(x-1, y+1, false) {
+                            self.push_box_up_down(x-1, y+1, false);
+                            self.move_robot(x, y+1);
+                        }
+                    }
+                    BigCell::Empty => {
+                        self.move_robot(x, y+1);
+                    },
+                    BigCell::Robot => unreachable!(),
+                }
+            },
+            Direction::West => {
+                match self.cells[y][x-1] {
+                    BigCell::Wall => { /* nothing to do */},
+                    BigCell::BoxR => {
+                        if self.can_push_box_left_right(x-1, y, true) {
+                            self.push_box_left_right(x-1, y, true);
+                            self.move_robot(x-1, y);
+                        }
+                    },
+                    BigCell::Empty => {
+                        self.move_robot(x-1, y);
+                    },
+                    BigCell::Robot | BigCell::BoxL => unreachable!(),
+                }
+            },
+            Direction::East => {
+                match self.cells[y][x+1] {
+                    BigCell::Wall => { /* nothing to do */},
+                    BigCell::BoxL => {
+                        if self.can_push_box_left_right(x+1, y, false) {
+                            self.push_box_left_right(x+1, y, false);
+                            self.move_robot(x+1, y);
+                        }
+                    },
+                    BigCell::Empty => {
+                        self.move_robot(x+1, y);
+                    },
+                    BigCell::Robot | BigCell::BoxR => unreachable!(),
+                }
+            },
+        }
+    }
+
+    fn move_robot(&mut self, new_x: usize, new_y: usize) {
+        self.cells[self.robot.y][self.robot.x] = BigCell::Empty;
+        self.cells[new_y][new_x] = BigCell::Robot;
+        self.robot = Position{x: new_x, y: new_y};
+    }
+
+    fn can_push_box_up_down(&self, x: usize, y: usize, go_up: bool) -> bool {
+        let new_y = if go_up { y - 1 } else { y + 1 };
+        match (self.cells[new_y][x], self.cells[new_y][x+1]) {
+            (BigCell::Wall, _) | (_, BigCell::Wall) => false,
+            (BigCell::Empty, BigCell::Empty) => true,
+            (BigCell::BoxL, BigCell::BoxR) => self.can_push_box_up_down(x, new_y, go_up),
+            (BigCell::BoxR, BigCell::BoxL) => self.can_push_box_up_down(x-1, new_y, go_up) && self.can_push_box_up_down(x+1, new_y, go_up),
+            (BigCell::BoxR, BigCell::Empty) => self.can_push_box_up_down(x-1, new_y, go_up),
+            (BigCell::Empty, BigCell::BoxL) => self.can_push_box_up_down(x+1, new_y, go_up),
+            _ => {
+                println!("{:?} {:?}", self.cells[new_y][x], self.cells[new_y][x+1]);
+                unreachable!()
+            },
+        }
+    }
+
+    fn push_box_up_down(&mut self, x: usize, y: usize, go_up: bool) {
+        let new_y = if go_up { y - 1 } else { y + 1 };
+        match (self.cells[new_y][x], self.cells[new_y][x+1]) {
+            (BigCell::Empty, BigCell::Empty) => {
+                self.move_box_up_down(x, y, go_up);
+            },
+            (BigCell::BoxL, BigCell::BoxR) => {
+                self.push_box_up_down(x, new_y, go_up);
+                self.move_box_up_down(x, y, go_up);
+            },
+            (BigCell::BoxR, BigCell::BoxL) => {
+                self.push_box_up_down(x-1, new_y, go_up);
+                self.push_box_up_down(x+1, new_y, go_up);
+                self.move_box_up_down(x, y, go_up);
+            },
+            (BigCell::BoxR, BigCell::Empty) => {
+                self.push_box_up_down(x-1, new_y, go_up);
+                self.move_box_up_down(x, y, go_up);
+            },
+            (BigCell::Empty, BigCell::BoxL) => {
+                self.push_box_up_down(x+1, new_y, go_up);
+                self.move_box_up_down(x, y, go_up);
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    fn move_box_up_down(&mut self, x: usize, y: usize, go_up: bool) {
+        let new_y = if go_up { y - 1 } else { y + 1 };
+        self.cells[new_y][x] = BigCell::BoxL;
+        self.cells[new_y][x+1] = BigCell::BoxR;
+        self.cells[y][x] = BigCell::Empty;
+        self.cells[y][x+1] = BigCell::Empty;
+    }
+
+    fn can_push_box_left_right(&self, x: usize, y: usize, go_left: bool) -> bool {
+        let new_x = if go_left { x - 1 } else { x + 1 };
+        match self.cells[y][new_x] {
+            BigCell::Wall => false,
+            BigCell::BoxL | BigCell::BoxR => self.can_push_box_left_right(new_x, y, go_left),
+            BigCell::Empty => true,
+            BigCell::Robot => unreachable!(),
+        }
+    }
+
+    fn push_box_left_right(&mut self, x: usize, y: usize, go_left: bool) {
+        let new_x = if go_left { x - 1 } else { x + 1 };
+        match self.cells[y][new_x] {
+            BigCell::BoxL | BigCell::BoxR => {
+                self.push_box_left_right(new_x, y, go_left);
+                self.move_box_left_right(x, y, go_left);
+            },
+            BigCell::Empty => {
+                self.move_box_left_right(x, y, go_left);
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    fn move_box_left_right(&mut self, x: usize, y: usize, go_left: bool) {
+        let new_x = if go_left { x - 1 } else { x + 1 };
+        self.cells[y][new_x] = self.cells[y][x];
+        self.cells[y][x] = BigCell::Empty;
+    }
+
+    pub fn sum_boxes_coordinates(&self) -> usize {
+        self.cells.iter().filter(|(_, cell)| **cell == BigCell::BoxL).map(|(position, _)| 100*position.y + position.x).sum()
+    }
+}
+
+impl Solution for Warehouse {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        let mut warehouse = self.clone();
+        warehouse.follow_instructions();
+        warehouse.sum_boxes_coordinates().into()
+    }
+
+    fn part2(&self) -> Answer {
+        let mut big_warehouse = self.scale_up();
+        big_warehouse.follow_instructions();
+        big_warehouse.sum_boxes_coordinates().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Warehouse;
+
+    #[test]
+    fn test_part1_small() {
+        let input = include_str!("../../input/day-15-test-small");
+        let mut warehouse = Warehouse::try_from(input).unwrap();
+        warehouse.follow_instructions();
+        let sum = warehouse.sum_boxes_coordinates();
+        assert_eq!(sum, 2028);
+    }
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../input/day-15-test");
+        let mut warehouse = Warehouse::try_from(input).unwrap();
+        warehouse.follow_instructions();
+        let sum = warehouse.sum_boxes_coordinates();
+        assert_eq!(sum, 10092);
+    }
+    
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../input/day-15-test");
+        let warehouse = Warehouse::try_from(input).unwrap();
+        let mut big_warehouse = warehouse.scale_up();
+        big_warehouse.draw();
+        big_warehouse.follow_instructions();
+        let sum = big_warehouse.sum_boxes_coordinates();
+        assert_eq!(sum, 9021);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let error = Warehouse::try_from("#####\n#.@O#\n#.X.#\n#####\n\n<^\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 3, col 3: unexpected `X`, expected `#`, `O`, `.` or `@`");
+        let error = Warehouse::try_from("#####\n#.@O.\n#####\n\n<^\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 2, col 5: expected a wall on the border of the map");
+        let error = Warehouse::try_from("#####\n#.@O#\n#####\n\n<^\nvv<x\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 6, col 4: unexpected `x`, expected `^`, `>`, `v` or `<`");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,227 @@
+use std::collections::HashMap;
+use common::{Answer, ParseError, Solution};
+use grid::{Direction, Grid, Position};
+
+#[derive(PartialEq)]
+enum Cell {
+    Wall,
+    Empty,
+    Start,
+    End,
+}
+
+impl TryFrom<char> for Cell {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '#' => Ok(Self::Wall),
+            '.' => Ok(Self::Empty),
+            'S' => Ok(Self::Start),
+            'E' => Ok(Self::End),
+            _ => Err(ParseError::new(format!("unexpected `{c}`, expected `#`, `.`, `S` or `E`"))),
+        }
+    }
+}
+
+pub struct Maze {
+    cells: Grid<Cell>,
+    start: Position,
+    end: Position,
+}
+
+impl Maze {
+    pub fn compute_lowest_score_and_tiles_number(&self) -> (u32, usize) {
+        let start = self.start;
+        let end = self.end;
+        let mut scores: HashMap<(Position, Direction), (u32, Vec<Position>)> = HashMap::new();
+
+        self.visit_maze_rec(start, Direction::East, 0, vec![], &mut scores);
+
+        // the end is reachable, parsing checked it
+        let (score, positions) = Direction::ALL.iter().filter_map(|d| 
+            scores.get(&(end, *d))
+        ).min_by_key(|(score, _)| score).unwrap();
+        let mut p = positions.clone();
+        p.sort();
+        p.dedup();
+        (*score, p.len())
+    }
+
+    fn visit_maze_rec(&self, position: Position, direction: Direction, score: u32, path: Vec<Position>, scores: &mut HashMap<(Position, Direction), (u32, Vec<Position>)>) {
+        let best_current_score = self.get_current_best_score(scores);
+
+        match self.cells[position] {
+            Cell::Wall => (),
+            Cell::Empty | Cell::Start => {
+                if let Some((best_score, positions)) = scores.get(&(position, direction)) {
+                    if let Some(best_score) = best_current_score {
+                        if best_score < score {
+                            return;
+                        }
+                    }
+                    if score < *best_score {
+                        let mut new_path = path;
+                        new_path.push(position);
+
+                        scores.insert((position, direction), (score, new_path.clone()));
+
+                        let new_position = move_forward(position, direction);
+                        let direction_left = direction.turn_left();
+                        let new_position_left = move_forward(position, direction_left);
+                        let direction_right = direction.turn_right();
+                        let new_position_right = move_forward(position, direction_right);
+                        self.visit_maze_rec(new_position, direction, score+1, new_path.clone(), scores);
+                        self.visit_maze_rec(new_position_left, direction_left, score+1001, new_path.clone(), scores);
+                        self.visit_maze_rec(new_position_right, direction_right, score+1001, new_path, scores);
+                    } else if score == *best_score {
+                        let mut new_path = path;
+                        new_path.push(position);
+                        let mut new_positions = positions.clone();
+                        new_positions.append(&mut new_path.clone());
+
+                        scores.insert((position, direction), (score, new_positions));
+
+                        let new_position = move_forward(position, direction);
+                        let direction_left = direction.turn_left();
+                        let new_position_left = move_forward(position, direction_left);
+                        let direction_right = direction.turn_right();
+                        let new_position_right = move_forward(position, direction_right);
+                        self.visit_maze_rec(new_position, direction, score+1, new_path.clone(), scores);
+                        self.visit_maze_rec(new_position_left, direction_left, score+1001, new_path.clone(), scores);
+                        self.visit_maze_rec(new_position_right, direction_right, score+1001, new_path, scores);
+                    }
+                } else {
+                    if let Some(best_score) = best_current_score {
+                        if best_score < score {
+                            return;
+                        }
+                    }
+                    let mut new_path = path;
+                    new_path.push(position);
+
+                    scores.insert((position, direction), (score, new_path.clone()));
+
+                    let new_position = move_forward(position, direction);
+                    let direction_left = direction.turn_left();
+                    let new_position_left = move_forward(position, direction_left);
+                    let direction_right = direction.turn_right();
+                    let new_position_right = move_forward(position, direction_right);
+                    self.visit_maze_rec(new_position, direction, score+1, new_path.clone(), scores);
+                    self.visit_maze_rec(new_position_left, direction_left, score+1001, new_path.clone(), scores);
+                    self.visit_maze_rec(new_position_right, direction_right, score+1001, new_path, scores);
+                }
+            },
+            Cell::End => {
+                if let Some((best_score, positions)) = scores.get(&(position, direction)) {
+                    if score < *best_score {
+                        let mut new_path = path;
+                        new_path.push(position);
+                        scores.insert((position, direction), (score, new_path));
+                    } else if score == *best_score {
+                        let mut new_path = path;
+                        new_path.push(position);
+                        let mut new_positions = positions.clone();
+                        new_positions.append(&mut new_path);
+
+                        scores.insert((position, direction), (score, new_positions));
+                    }
+                } else {
+                    let mut new_path = path;
+                    new_path.push(position);
+                    scores.insert((position, direction), (score, new_path));
+                }
+            },
+        }
+    }
+
+    fn get_current_best_score(&self, scores: &HashMap<(Position, Direction), (u32, Vec<Position>)>) -> Option<u32> {
+        let end = self.end;
+        let best = Direction::ALL.iter().filter_map(|d| 
+            scores.get(&(end, *d))
+        ).min_by_key(|(score, _)| score);
+        best.map(|best| best.0)
+    }
+}
+
+impl TryFrom<&str> for Maze {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let cells = Grid::parse(input, Cell::try_from)?;
+        cells.check_walled(|cell| *cell == Cell::Wall)?;
+        let start = cells.find_unique(|cell| *cell == Cell::Start, "start `S`")?;
+        let end = cells.find_unique(|cell| *cell == Cell::End, "end `E`")?;
+        cells.check_reachable(start, end, |cell| *cell == Cell::Wall, ("start `S`", "end `E`"))?;
+
+        Ok(Self { cells, start, end })
+    }
+}
+
+// the maze is walled, so the reindeer never walks out of it
+fn move_forward(position: Position, direction: Direction) -> Position {
+    position.step(direction).unwrap()
+}
+
+impl Solution for Maze {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        let (score, _) = self.compute_lowest_score_and_tiles_number();
+        score.into()
+    }
+
+    fn part2(&self) -> Answer {
+        let (_, tiles) = self.compute_lowest_score_and_tiles_number();
+        tiles.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Maze;
+
+    #[test]
+    fn test_part1_small() {
+        let input = include_str!("../../input/day-16-test-small");
+        let maze = Maze::try_from(input).unwrap();
+        let (score, _) = maze.compute_lowest_score_and_tiles_number();
+        assert_eq!(score, 7036);
+    }
+    
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../input/day-16-test");
+        let maze = Maze::try_from(input).unwrap();
+        let (score, _) = maze.compute_lowest_score_and_tiles_number();
+        assert_eq!(score, 11048);
+    }
+    
+    #[test]
+    fn test_part2_small() {
+        let input = include_str!("../../input/day-16-test-small");
+        let maze = Maze::try_from(input).unwrap();
+        let (_, tiles) = maze.compute_lowest_score_and_tiles_number();
+        assert_eq!(tiles, 45);
+    }
+    
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../input/day-16-test");
+        let maze = Maze::try_from(input).unwrap();
+        let (_, tiles) = maze.compute_lowest_score_and_tiles_number();
+        assert_eq!(tiles, 64);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let error = Maze::try_from("#####\n#.?E#\n#S..#\n#####\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 2, col 3: unexpected `?`, expected `#`, `.`, `S` or `E`");
+        let error = Maze::try_from("#####\n#..E#\n#...#\n#####\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 5, col 1: unexpected end of input, no start `S` found");
+        let error = Maze::try_from("#####\n#S#E#\n#####\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 2, col 2: no way from the start `S` to the end `E`");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,248 @@
+use common::{parse::{lines, Cursor}, Answer, ParseError, Solution};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Computer {
+    ra: u64,
+    rb: u64,
+    rc: u64,
+    instructions: Vec<u8>,
+}
+
+impl Computer {
+    // 7 is reserved, parsing rejects it
+    fn get_combo(&self, combo: u8) -> u64 {
+        match combo {
+            4 => self.ra,
+            5 => self.rb,
+            6 => self.rc,
+            _ => combo as u64,
+        }
+    }
+
+    // `ra / 2^combo`, which is 0 once the power doesn't fit in 64 bits
+    fn divide(&self, combo: u8) -> u64 {
+        u32::try_from(self.get_combo(combo)).ok().and_then(|shift| self.ra.checked_shr(shift)).unwrap_or(0)
+    }
+
+    pub fn run_program(&mut self) -> Vec<String> {
+        let mut outputs: Vec<String> = vec![];
+        let mut pointer = 0;
+
+        // a jump to the last number leaves no operand to read, which halts too
+        while pointer + 1 < self.instructions.len() {
+            let op = self.instructions[pointer];
+            let operand = self.instructions[pointer + 1];
+            match op {
+                0 => { // adv
+                    self.ra = self.divide(operand);
+                    pointer += 2;
+                },
+                1 => { // bxl
+                    self.rb ^= operand as u64;
+                    pointer += 2;
+                },
+                2 => { // bst
+                    self.rb = self.get_combo(operand) % 8;
+                    pointer += 2;
+                },
+                3 => { // jnz
+                    if self.ra == 0 {
+                        pointer += 2;
+                    } else {
+                        pointer = operand as usize;
+                    }
+                },
+                4 => { // bxc
+                    self.rb ^= self.rc;
+                    pointer += 2;
+                },
+                5 => { // out
+                    let value_to_push = self.get_combo(operand) % 8;
+                    outputs.push(value_to_push.to_string());
+                    pointer += 2;
+                },
+                6 => { // bdv
+                    self.rb = self.divide(operand);
+                    pointer += 2;
+                },
+                7 => { // cdv
+                    self.rc = self.divide(operand);
+                    pointer += 2;
+                },
+                _ => unreachable!(),
+            }
+        }
+        outputs
+    }
+
+    /// The lowest value of register A for which the program prints itself, found by fixing the outputs
+    /// from the last one, for programs that print 3 bits of A per round. `None` when the search runs
+    /// out of values with as many outputs as the program has numbers.
+    pub fn find_register(&mut self) -> Option<u64> {
+        let instructions: Vec<String> = self.instructions.iter().map(|i| i.to_string()).collect();
+        // each output consumes 3 bits of register A, so printing the whole program needs A >= 8^(len - 1)
+        let mut ra = 8u64.checked_pow(self.instructions.len() as u32 - 1)?;
+
+        loop {
+            self.ra = ra;
+            self.rb = 0;
+            self.rc = 0;
+            let output = self.run_program();
+
+            if output == instructions {
+                return Some(ra);
+            }
+            // past the values printing that many outputs, or a program of another shape
+            if output.len() != instructions.len() {
+                return None;
+            }
+            let len = output.len();
+            let mut i = 0;
+            while output[len - i - 1] == instructions[len - i - 1] {
+                i += 1;
+            }
+            ra = ra.checked_add(8u64.pow((len - i - 1) as u32))?;
+        }
+    }
+}
+
+impl TryFrom<&str> for Computer {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut lines = lines(input);
+        let mut next_line = |expected: &str| lines.next().ok_or_else(|| ParseError::end_of_input(input, format!("expected `{expected}`")));
+        let ra = parse_register(&mut next_line("Register A: ")?, "Register A: ")?;
+        let rb = parse_register(&mut next_line("Register B: ")?, "Register B: ")?;
+        let rc = parse_register(&mut next_line("Register C: ")?, "Register C: ")?;
+        next_line("an empty line")?.finish()?;
+
+        let mut cursor = next_line("Program: ")?;
+        cursor.expect("Program: ")?;
+        // where each number starts, to point at reserved operands
+        let mut columns = vec![cursor.column()];
+        let mut instructions = vec![parse_three_bits(&mut cursor)?];
+        while cursor.eat(",") {
+            columns.push(cursor.column());
+            instructions.push(parse_three_bits(&mut cursor)?);
+        }
+        cursor.finish()?;
+        // every instruction comes with its operand
+        if instructions.len() % 2 == 1 {
+            return Err(cursor.error("expected an operand after the last instruction"));
+        }
+        // bxl, jnz and bxc take their operand literally, the others as a combo operand
+        if let Some(index) = (0..instructions.len()).step_by(2).find(|&i| !matches!(instructions[i], 1 | 3 | 4) && instructions[i + 1] == 7) {
+            return Err(ParseError::at(cursor.line(), columns[index + 1], "combo operand 7 is reserved"));
+        }
+
+        Ok(Self { ra, rb, rc, instructions })
+    }
+}
+
+fn parse_register(cursor: &mut Cursor, prefix: &str) -> Result<u64, ParseError> {
+    cursor.expect(prefix)?;
+    let value = cursor.number::<u64>()?;
+    cursor.finish()?;
+    Ok(value)
+}
+
+fn parse_three_bits(cursor: &mut Cursor) -> Result<u8, ParseError> {
+    let column = cursor.column();
+    match cursor.number::<u8>()? {
+        value @ 0..=7 => Ok(value),
+        value => Err(ParseError::at(cursor.line(), column, format!("expected a 3-bit number, found {value}"))),
+    }
+}
+
+impl Solution for Computer {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        self.clone().run_program().join(",").into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.clone().find_register().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{Answer, Solution};
+    use proptest::prelude::*;
+    use crate::Computer;
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../input/day-17-test");
+        let mut computer = Computer::try_from(input).unwrap();
+        let output = computer.run_program();
+        assert_eq!(output.join(","), "4,6,3,5,6,3,5,2,1,0".to_string());
+    }
+    
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../input/day-17-test-part2");
+        let mut computer = Computer::try_from(input).unwrap();
+        let ra = computer.find_register();
+        assert_eq!(ra, Some(117440));
+    }
+
+    #[test]
+    fn test_no_register() {
+        // prints 3 bits of A then stops, so it never prints its 4 numbers
+        let input = "Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 5,4,0,3\n";
+        let mut computer = Computer::try_from(input).unwrap();
+        assert_eq!(computer.find_register(), None);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let error = Computer::try_from("Register A: 729\nRegister B: 0\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 3, col 1: unexpected end of input, expected `Register C: `");
+        let error = Computer::try_from("Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,8,3,0\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 5, col 16: expected a 3-bit number, found 8");
+        let error = Computer::try_from("Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 5, col 15: expected an operand after the last instruction");
+        let error = Computer::try_from("Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,7,1,7\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 5, col 12: combo operand 7 is reserved");
+        // bxl takes 7 as a number
+        assert!(Computer::try_from("Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 1,7\n").is_ok());
+    }
+
+    #[test]
+    fn test_huge_shifts() {
+        // A shifted by B = 2^40 bits is 0, and so is C, a copy of it
+        let input = "Register A: 18446744073709551615\nRegister B: 1099511627776\nRegister C: 0\n\nProgram: 0,5,5,4,7,6,5,6\n";
+        let computer = Computer::try_from(input).unwrap();
+        assert_eq!(computer.part1(), Answer::from("0,0"));
+        // a jump to the last number halts instead of reading past the end
+        let input = "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 3,1\n";
+        assert_eq!(Computer::try_from(input).unwrap().part1(), Answer::from(""));
+    }
+
+    fn to_input(computer: &Computer) -> String {
+        let program: Vec<String> = computer.instructions.iter().map(|i| i.to_string()).collect();
+        format!("Register A: {}\nRegister B: {}\nRegister C: {}\n\nProgram: {}\n", computer.ra, computer.rb, computer.rc, program.join(","))
+    }
+
+    fn computer() -> impl Strategy<Value = Computer> {
+        let instructions = prop::collection::vec((0..8u8, 0..8u8), 1..10)
+            .prop_map(|pairs| pairs.into_iter().flat_map(|(op, operand)| match (op, operand) {
+                // combo operand 7 is reserved
+                (0 | 2 | 5 | 6 | 7, 7) => [op, 6],
+                _ => [op, operand],
+            }).collect());
+        (any::<u64>(), any::<u64>(), any::<u64>(), instructions).prop_map(|(ra, rb, rc, instructions)| Computer { ra, rb, rc, instructions })
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(computer in computer()) {
+            prop_assert_eq!(Computer::try_from(to_input(&computer).as_str()), Ok(computer));
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,142 @@
+use common::{parse::{parse_lines, Cursor}, Answer, ParseError, Solution};
+use grid::{Connectivity, Grid, Position};
+
+const MEMORY_SIZE: usize = 71;
+const FALLEN_BYTES_PART_1: usize = 1024;
+
+#[derive(PartialEq, Clone, Copy)]
+enum MemByte {
+    Corrupted,
+    Ok,
+}
+
+pub struct Memory {
+    bytes: Grid<MemByte>
+}
+
+impl Memory {
+    pub fn new(width: usize, height: usize, fallen_bytes: &[Position]) -> Self {
+        let mut bytes = Grid::new(width, height, MemByte::Ok);
+
+        fallen_bytes.iter().for_each(|&position| {
+            bytes[position] = MemByte::Corrupted;
+        });
+
+        Self { bytes }
+    }
+
+    /// `None` when the bytes cut the exit off.
+    pub fn compute_steps_to_exit(&self) -> Option<usize> {
+        let mut distances = Grid::new(self.bytes.width(), self.bytes.height(), usize::MAX);
+        self.compute_steps_to_exit_rec(Position::new(0, 0), 0, &mut distances);
+        Some(distances[Position::new(self.bytes.width() - 1, self.bytes.height() - 1)]).filter(|&distance| distance < usize::MAX)
+    }
+
+    fn compute_steps_to_exit_rec(&self, position: Position, distance: usize, distances: &mut Grid<usize>) {
+        if self.bytes[position] == MemByte::Corrupted || distance >= distances[position] {
+            return;
+        }
+        distances[position] = distance;
+        for neighbor in self.bytes.neighbors(position, Connectivity::Four) {
+            self.compute_steps_to_exit_rec(neighbor, distance+1, distances);
+        }
+    }
+}
+
+/// The first byte that cuts the exit off, `None` if the exit is still reachable once they all fell.
+pub fn get_blocking_byte(width: usize, height: usize, fallen_bytes: &[Position]) -> Option<String> {
+    let mut index = fallen_bytes.len();
+    loop {
+        let memory = Memory::new(width, height, &fallen_bytes[..index]);
+        if memory.compute_steps_to_exit().is_some() {
+            break;
+        }
+        index -= 1;
+    }
+    let Position { x, y } = fallen_bytes.get(index)?;
+    Some(format!("{x},{y}"))
+}
+
+pub struct FallingBytes {
+    bytes: Vec<Position>,
+}
+
+impl TryFrom<&str> for FallingBytes {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let bytes = parse_lines(input, |cursor| {
+            let x = parse_coordinate(cursor)?;
+            cursor.expect(",")?;
+            let y = parse_coordinate(cursor)?;
+            Ok(Position::new(x, y))
+        })?;
+        Ok(Self { bytes })
+    }
+}
+
+fn parse_coordinate(cursor: &mut Cursor) -> Result<usize, ParseError> {
+    let column = cursor.column();
+    match cursor.number::<usize>()? {
+        value if value < MEMORY_SIZE => Ok(value),
+        value => Err(ParseError::at(cursor.line(), column, format!("coordinate {value} is outside of the {MEMORY_SIZE}x{MEMORY_SIZE} memory"))),
+    }
+}
+
+impl Solution for FallingBytes {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        let fallen_bytes = &self.bytes[..FALLEN_BYTES_PART_1.min(self.bytes.len())];
+        Memory::new(MEMORY_SIZE, MEMORY_SIZE, fallen_bytes).compute_steps_to_exit().into()
+    }
+
+    fn part2(&self) -> Answer {
+        get_blocking_byte(MEMORY_SIZE, MEMORY_SIZE, &self.bytes).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{Answer, Solution};
+    use crate::{get_blocking_byte, FallingBytes, Memory};
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../input/day-18-test");
+        let falling_bytes = FallingBytes::try_from(input).unwrap();
+        let memory = Memory::new(7, 7, &falling_bytes.bytes[..12]);
+        let distance = memory.compute_steps_to_exit();
+        assert_eq!(distance, Some(22));
+    }
+    
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../input/day-18-test");
+        let falling_bytes = FallingBytes::try_from(input).unwrap();
+        let blocking_byte = get_blocking_byte(7, 7, &falling_bytes.bytes);
+        assert_eq!(blocking_byte, Some("6,1".to_string()));
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let error = FallingBytes::try_from("5,4\n4;2\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 2, col 2: expected `,`");
+        let error = FallingBytes::try_from("5,4\n100,70\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 2, col 1: coordinate 100 is outside of the 71x71 memory");
+        let error = FallingBytes::try_from("5,71\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 1, col 3: coordinate 71 is outside of the 71x71 memory");
+    }
+
+    #[test]
+    fn test_no_answer() {
+        // the exit is never cut off
+        let falling_bytes = FallingBytes::try_from("5,4\n").unwrap();
+        assert_eq!(falling_bytes.part2(), Answer::NotFound);
+        // and here it's the start that is corrupted
+        let falling_bytes = FallingBytes::try_from("0,0\n").unwrap();
+        assert_eq!(falling_bytes.part1(), Answer::NotFound);
+    }
+}
\ No newline at end of file
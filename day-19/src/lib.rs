@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+use common::{parse::{lines, Cursor}, Answer, ParseError, Solution};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+#[derive(Debug, PartialEq)]
+pub struct Onsen {
+    towels: Vec<String>,
+    patterns: Vec<String>,
+}
+
+impl Onsen {
+    pub fn count_possible_patterns(&self) -> usize {
+        let possible: Vec<_> = self.patterns.iter().filter(|pattern| {
+            is_pattern_possible_rec(pattern, &self.towels, 0)
+        }).collect();
+        possible.len()
+    }
+
+    pub fn count_all_possible_combinations(&self) -> usize {
+        #[cfg(feature = "parallel")]
+        let patterns = self.patterns.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let patterns = self.patterns.iter();
+        patterns.map(|pattern| {
+            let mut cache: HashMap<usize, usize> = HashMap::new();
+            all_possible_patterns(pattern, &self.towels, 0, &mut cache)
+        }
+        ).sum()
+    }
+}
+
+fn is_pattern_possible_rec(pattern: &str, towels: &Vec<String>, index: usize) -> bool {
+    index == pattern.len() ||
+        towels.iter().any(|towel| pattern[index..].starts_with(towel) && is_pattern_possible_rec(pattern, towels, index + towel.len()))
+}
+
+fn all_possible_patterns(pattern: &str, towels: &Vec<String>, index: usize, cache: &mut HashMap<usize, usize>) -> usize {
+    if index == pattern.len() {
+        1
+    } else {
+        if let Some(count) = cache.get(&index) {
+            *count
+        } else {
+            let count = towels.iter().map(|towel| {
+                if pattern[index..].starts_with(towel) {
+                    all_possible_patterns(pattern, towels, index + towel.len(), cache)
+                } else {
+                    0
+                }
+            }).sum();
+            cache.insert(index, count);
+            count
+        }
+    }
+}
+
+impl TryFrom<&str> for Onsen {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut lines = lines(input);
+        let mut cursor = lines.next().ok_or_else(|| ParseError::end_of_input(input, "expected towels"))?;
+        let mut towels = vec![parse_stripes(&mut cursor, "towel")?];
+        while cursor.eat(", ") {
+            towels.push(parse_stripes(&mut cursor, "towel")?);
+        }
+        cursor.finish()?;
+        lines.next().ok_or_else(|| ParseError::end_of_input(input, "expected an empty line"))?.finish()?;
+
+        let patterns = lines.map(|mut cursor| {
+            let pattern = parse_stripes(&mut cursor, "pattern")?;
+            cursor.finish()?;
+            Ok(pattern)
+        }).collect::<Result<_, ParseError>>()?;
+        Ok(Self { towels, patterns })
+    }
+}
+
+// an empty towel would never let the pattern matching move forward
+fn parse_stripes(cursor: &mut Cursor, name: &str) -> Result<String, ParseError> {
+    let stripes = cursor.rest().chars().take_while(|c| c.is_ascii_alphabetic()).count();
+    if stripes == 0 {
+        return Err(cursor.error(format!("expected a {name}")));
+    }
+    Ok((0..stripes).filter_map(|_| cursor.next_char()).collect())
+}
+
+impl Solution for Onsen {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        self.count_possible_patterns().into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.count_all_possible_combinations().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, vec};
+    use proptest::prelude::*;
+    use crate::{all_possible_patterns, is_pattern_possible_rec, Onsen};
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../input/day-19-test");
+        let onsen = Onsen::try_from(input).unwrap();
+        let possible_patterns = onsen.count_possible_patterns();
+        assert_eq!(possible_patterns, 6);
+    }
+
+    #[test]
+    fn test_part1_pattern() {
+        let pattern = "brwrr";
+        let towels = vec!["r".to_string(), "wr".to_string(), "b".to_string(), "g".to_string(), "bwu".to_string(), "rb".to_string(), "gb".to_string(), "br".to_string()];
+        assert!(is_pattern_possible_rec(pattern, &towels, 0));
+    }
+    
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../input/day-19-test");
+        let onsen = Onsen::try_from(input).unwrap();
+        let combinations = onsen.count_all_possible_combinations();
+        assert_eq!(combinations, 16);
+    }
+
+    #[test]
+    fn test_part2_pattern() {
+        let pattern = "bggr";
+        let towels = vec!["r".to_string(), "wr".to_string(), "b".to_string(), "g".to_string(), "bwu".to_string(), "rb".to_string(), "gb".to_string(), "br".to_string()];
+        let mut cache = HashMap::new();
+        assert_eq!(all_possible_patterns(pattern, &towels, 0, &mut cache), 1);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let error = Onsen::try_from("r, wr, , b\n\nbrwrr\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 1, col 8: expected a towel");
+        let error = Onsen::try_from("r, wr, b\nbrwrr\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 2, col 1: unexpected `b`");
+        let error = Onsen::try_from("r, wr, b\n\nbrwrr\nbw-r\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 4, col 3: unexpected `-`");
+    }
+
+    fn to_input(onsen: &Onsen) -> String {
+        format!("{}\n\n{}", onsen.towels.join(", "), onsen.patterns.iter().map(|pattern| format!("{pattern}\n")).collect::<String>())
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(towels in prop::collection::vec("[wubrg]{1,8}", 1..10), patterns in prop::collection::vec("[wubrg]{1,40}", 0..10)) {
+            let onsen = Onsen { towels, patterns };
+            prop_assert_eq!(Onsen::try_from(to_input(&onsen).as_str()), Ok(onsen));
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,218 @@
+use std::collections::HashMap;
+use common::{Answer, ParseError, Solution};
+use grid::{Connectivity, Direction, Grid, Position};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+const MINIMUM_SAVING: u32 = 100;
+
+#[derive(PartialEq, Clone, Copy)]
+enum Cell {
+    Wall,
+    Empty,
+    Start,
+    End,
+}
+
+impl Cell {
+    fn is_on_path(&self) -> bool {
+        matches!(self, Cell::Empty | Cell::End)
+    }
+}
+
+impl TryFrom<char> for Cell {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '#' => Ok(Self::Wall),
+            '.' => Ok(Self::Empty),
+            'S' => Ok(Self::Start),
+            'E' => Ok(Self::End),
+            _ => Err(ParseError::new(format!("unexpected `{c}`, expected `#`, `.`, `S` or `E`"))),
+        }
+    }
+}
+
+pub struct Cpu {
+    cells: Grid<Cell>,
+    start: Position,
+    end: Position,
+}
+
+impl TryFrom<&str> for Cpu {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let cells = Grid::parse(input, Cell::try_from)?;
+        // the race follows the track without bound checks
+        cells.check_walled(|cell| cell == &Cell::Wall)?;
+
+        let start = cells.find_unique(|cell| cell == &Cell::Start, "start `S`")?;
+        let end = cells.find_unique(|cell| cell == &Cell::End, "end `E`")?;
+
+        let cpu = Self { cells, start, end };
+        if cpu.follow_track().is_none() {
+            return Err(ParseError::at(start.y + 1, start.x + 1, "the track from the start `S` doesn't lead to the end `E`"));
+        }
+        Ok(cpu)
+    }
+}
+
+impl Cpu {
+    pub fn compute_path(&self) -> (Vec<Position>, HashMap<Position, u32>) {
+        // parsing checked that the track leads to the end
+        let positions = self.follow_track().unwrap();
+        let distance_from_start = positions.iter().zip(0..).map(|(&position, distance)| (position, distance)).collect();
+        (positions, distance_from_start)
+    }
+
+    // the positions from the start to the end, `None` if the track stops or loops before the end
+    fn follow_track(&self) -> Option<Vec<Position>> {
+        let mut positions = vec![self.start];
+        let mut previous_position = self.start;
+        let mut current_position = self.start;
+        while current_position != self.end {
+            let next_position = self.find_next_move(current_position, previous_position)?;
+            previous_position = current_position;
+            current_position = next_position;
+            positions.push(current_position);
+            // a loop would go on forever
+            if positions.len() > self.cells.width() * self.cells.height() {
+                return None;
+            }
+        }
+        Some(positions)
+    }
+
+    pub fn compute_shortcuts(&self, path: Vec<Position>, distances: HashMap<Position, u32>) -> HashMap<(Position, Direction), u32> {
+        let mut shortcuts = HashMap::new();
+        for position in path {
+            let distance = distances.get(&position).unwrap();
+            for direction in Direction::ALL {
+                let two_steps = self.cells.step(position, direction).and_then(|next| self.cells.step(next, direction));
+                if let Some(other_distance) = two_steps.and_then(|two_steps| distances.get(&two_steps)) {
+                    if distance + 2 < *other_distance {
+                        shortcuts.insert((position, direction), other_distance - distance - 2);
+                    }
+                }
+            }
+        }
+        shortcuts
+    }
+
+    pub fn compute_super_shortcuts(&self, path: Vec<Position>, distances: HashMap<Position, u32>) -> Vec<u32> {
+        let shortcuts_from = |position: Position| {
+            let distance = distances.get(&position).unwrap();
+            self.reachable_positions_with_shortcut(position, 20).into_iter().filter_map(|candidate_position| {
+                let other_distance = distances.get(&candidate_position)?;
+                let distance_to_other = position.manhattan_distance(&candidate_position) as u32;
+                (distance + distance_to_other < *other_distance).then(|| other_distance - distance - distance_to_other)
+            }).collect::<Vec<u32>>()
+        };
+        // collecting keeps the order of `path` either way
+        #[cfg(feature = "parallel")]
+        let shortcuts = path.into_par_iter().flat_map_iter(shortcuts_from).collect();
+        #[cfg(not(feature = "parallel"))]
+        let shortcuts = path.into_iter().flat_map(shortcuts_from).collect();
+        shortcuts
+    }
+
+    fn reachable_positions_with_shortcut(&self, position: Position, length: usize) -> Vec<Position> {
+        self.cells.positions().filter(|candidate_position| position.manhattan_distance(candidate_position) <= length).collect()
+    }
+
+    // the track has no forks, there's only one way to go forward
+    fn find_next_move(&self, current_position: Position, previous_position: Position) -> Option<Position> {
+        self.cells.neighbors(current_position, Connectivity::Four)
+            .find(|&position| self.cells[position].is_on_path() && position != previous_position)
+    }
+}
+
+impl Solution for Cpu {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        let (path, distances) = self.compute_path();
+        let shortcuts = self.compute_shortcuts(path, distances);
+        shortcuts.values().filter(|value| **value >= MINIMUM_SAVING).count().into()
+    }
+
+    fn part2(&self) -> Answer {
+        let (path, distances) = self.compute_path();
+        let super_shortcuts = self.compute_super_shortcuts(path, distances);
+        super_shortcuts.iter().filter(|value| **value >= MINIMUM_SAVING).count().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Cpu;
+
+    #[test]
+    fn test_part1_shortest_path() {
+        let input = include_str!("../../input/day-20-test");
+        let cpu = Cpu::try_from(input).unwrap();
+        let (_, shortest_path) = cpu.compute_path();
+        assert_eq!(shortest_path.get(&cpu.end), Some(&84));
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../input/day-20-test");
+        let cpu = Cpu::try_from(input).unwrap();
+        let (path, distances) = cpu.compute_path();
+        let shortcuts = cpu.compute_shortcuts(path, distances);
+        let shortcut_values: Vec<_> = shortcuts.values().collect();
+        assert_eq!(shortcut_values.iter().filter(|&&d| *d == 2).count(), 14);
+        assert_eq!(shortcut_values.iter().filter(|&&d| *d == 4).count(), 14);
+        assert_eq!(shortcut_values.iter().filter(|&&d| *d == 6).count(), 2);
+        assert_eq!(shortcut_values.iter().filter(|&&d| *d == 8).count(), 4);
+        assert_eq!(shortcut_values.iter().filter(|&&d| *d == 10).count(), 2);
+        assert_eq!(shortcut_values.iter().filter(|&&d| *d == 12).count(), 3);
+        assert_eq!(shortcut_values.iter().filter(|&&d| *d == 20).count(), 1);
+        assert_eq!(shortcut_values.iter().filter(|&&d| *d == 36).count(), 1);
+        assert_eq!(shortcut_values.iter().filter(|&&d| *d == 38).count(), 1);
+        assert_eq!(shortcut_values.iter().filter(|&&d| *d == 40).count(), 1);
+        assert_eq!(shortcut_values.iter().filter(|&&d| *d == 64).count(), 1);
+    }
+    
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../input/day-20-test");
+        let cpu = Cpu::try_from(input).unwrap();
+        let (path, distances) = cpu.compute_path();
+        let super_shortcut = cpu.compute_super_shortcuts(path, distances);
+        assert_eq!(super_shortcut.iter().filter(|&&value| value == 50).count(), 32);
+        assert_eq!(super_shortcut.iter().filter(|&&value| value == 52).count(), 31);
+        assert_eq!(super_shortcut.iter().filter(|&&value| value == 54).count(), 29);
+        assert_eq!(super_shortcut.iter().filter(|&&value| value == 56).count(), 39);
+        assert_eq!(super_shortcut.iter().filter(|&&value| value == 58).count(), 25);
+        assert_eq!(super_shortcut.iter().filter(|&&value| value == 60).count(), 23);
+        assert_eq!(super_shortcut.iter().filter(|&&value| value == 62).count(), 20);
+        assert_eq!(super_shortcut.iter().filter(|&&value| value == 64).count(), 19);
+        assert_eq!(super_shortcut.iter().filter(|&&value| value == 66).count(), 12);
+        assert_eq!(super_shortcut.iter().filter(|&&value| value == 68).count(), 14);
+        assert_eq!(super_shortcut.iter().filter(|&&value| value == 70).count(), 12);
+        assert_eq!(super_shortcut.iter().filter(|&&value| value == 72).count(), 22);
+        assert_eq!(super_shortcut.iter().filter(|&&value| value == 74).count(), 4);
+        assert_eq!(super_shortcut.iter().filter(|&&value| value == 76).count(), 3);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let error = Cpu::try_from("#####\n#S.E#\n#..x#\n#####\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 3, col 4: unexpected `x`, expected `#`, `.`, `S` or `E`");
+        let error = Cpu::try_from("#####\n#S.EE\n#####\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 2, col 5: expected a wall on the border of the map");
+        let error = Cpu::try_from("#####\n#S.E#\n#.E.#\n#####\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 3, col 3: found a second end `E`");
+        let error = Cpu::try_from("#####\n#S#E#\n#####\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 2, col 2: the track from the start `S` doesn't lead to the end `E`");
+        // going around in circles, the way north coming before the way to the end
+        let error = Cpu::try_from("######\n#S..##\n##..##\n##E###\n######\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 2, col 2: the track from the start `S` doesn't lead to the end `E`");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+use common::{parse::parse_lines, Answer, ParseError, Solution};
+
+fn numeric_keypad_button_to_position(button: char) -> (i32, i32) {
+    match button {
+        'A' => (2, 3),
+        '0' => (1, 3),
+        '1' => (0, 2),
+        '2' => (1, 2),
+        '3' => (2, 2),
+        '4' => (0, 1),
+        '5' => (1, 1),
+        '6' => (2, 1),
+        '7' => (0, 0),
+        '8' => (1, 0),
+        '9' => (2, 0),
+        _ => unreachable!()
+    }
+}
+
+fn arrow_keypad_button_to_position(button: char) -> (i32, i32) {
+    match button {
+        'A' => (2, 0),
+        '^' => (1, 0),
+        '<' => (0, 1),
+        'v' => (1, 1),
+        '>' => (2, 1),
+        _ => unimplemented!(),
+    }
+}
+
+fn paths_to_next_coordinate_numeric((ax, ay): (i32, i32), (bx, by): (i32, i32)) -> Vec<String> {
+    let dx = bx - ax;
+    let dy = by - ay;
+    if dx != 0 || dy != 0 {
+        let mut result = vec![];
+        let mut horizontal_path = "".to_string();
+        if dx > 0 {
+            for _ in 0..dx { horizontal_path.push('>'); }
+        }
+        if dx < 0 {
+            for _ in 0..dx.abs() { horizontal_path.push('<'); }
+        }
+    
+        let mut vertical_path = "".to_string();
+        if dy > 0 {
+            for _ in 0..dy { vertical_path.push('v'); }
+        }
+        if dy < 0 {
+            for _ in 0..dy.abs() { vertical_path.push('^'); }
+        }
+        if ay != 3 || bx != 0 { 
+            result.push(format!("{}{}A", &horizontal_path, &vertical_path));
+        }
+        if by != 3 || ax != 0 {
+            result.push(format!("{}{}A", &vertical_path, &horizontal_path));
+        }
+        result
+    } else {
+        vec!["A".to_string()]
+    }
+}
+
+fn paths_to_next_coordinate_arrow((ax, ay): (i32, i32), (bx, by): (i32, i32)) -> Vec<String> {
+    let dx = bx - ax;
+    let dy = by - ay;
+    if dx != 0 || dy != 0 {
+        let mut result = vec![];
+        let mut horizontal_path = "".to_string();
+        if dx > 0 {
+            for _ in 0..dx { horizontal_path.push('>'); }
+        }
+        if dx < 0 {
+            for _ in 0..dx.abs() { horizontal_path.push('<'); }
+        }
+    
+        let mut vertical_path = "".to_string();
+        if dy > 0 {
+            for _ in 0..dy { vertical_path.push('v'); }
+        }
+        if dy < 0 {
+            for _ in 0..dy.abs() { vertical_path.push('^'); }
+        }
+        if ay != 0 || bx != 0 { 
+            result.push(format!("{}{}A", &horizontal_path, &vertical_path));
+        }
+        if by != 0 || ax != 0 {
+            result.push(format!("{}{}A", &vertical_path, &horizontal_path));
+        }
+        result.sort();
+        result.dedup();
+        result
+    } else {
+        vec!["A".to_string()]
+    }
+}
+
+fn arrow_to_shortest_path(button1: char, button2: char, level: usize, cache: &mut HashMap<(char, char, usize), usize>) -> usize {
+    let paths = paths_to_next_coordinate_arrow(arrow_keypad_button_to_position(button1), arrow_keypad_button_to_position(button2));
+    if level == 0 {
+        paths.iter().map(|path| path.len()).min().unwrap()
+    } else {
+        let path = paths.iter().map(|path| {
+            let buttons: Vec<char> = ['A'].into_iter().chain(path.chars()).collect();
+            buttons.windows(2).map(|buttons| {
+                let button1 = buttons[0];
+                let button2 = buttons[1];
+                if let Some(&len) = cache.get(&(button1, button2, level - 1)) {
+                    len
+                } else {
+                    let len = arrow_to_shortest_path(button1, button2, level - 1, cache);
+                    cache.insert((button1, button2, level - 1), len);
+                    len
+                }
+            }).sum()
+        }).min().unwrap();
+        path
+    }
+}
+
+fn numeric_to_shortest_final_path(button1: char, button2: char, level: usize) -> usize {
+    let paths = paths_to_next_coordinate_numeric(numeric_keypad_button_to_position(button1), numeric_keypad_button_to_position(button2));
+    //println!("{} -> {} has potential paths {:?}", button1, button2, paths);
+    let mut cache: HashMap<(char, char, usize), usize> = HashMap::new();
+    paths.iter().map(|path| {
+        let buttons: Vec<char> = ['A'].into_iter().chain(path.chars()).collect();
+        buttons.windows(2).map(|buttons|{
+            let button1 = buttons[0];
+            let button2 = buttons[1];
+            if let Some(&len) = cache.get(&(button1, button2, level-1)) {
+                len
+            } else {
+                let len = arrow_to_shortest_path(button1, button2, level - 1, &mut cache);
+                cache.insert((button1, button2, level-1), len);
+                len
+            }
+        }).sum()
+    }).min().unwrap()
+}
+
+pub fn numeric_sequence_to_final_sequence_length_part1(sequence: String) -> usize {
+    let buttons: Vec<char> = ['A'].into_iter().chain(sequence.chars()).collect();
+    buttons.windows(2).map(|buttons|{
+        let button1 = buttons[0];
+        let button2 = buttons[1];
+        numeric_to_shortest_final_path(button1, button2, 2)
+    }).sum()
+}
+
+fn numeric_sequence_to_final_sequence_length_part2(sequence: String) -> usize {
+    let buttons: Vec<char> = ['A'].into_iter().chain(sequence.chars()).collect();
+    buttons.windows(2).map(|buttons|{
+        let button1 = buttons[0];
+        let button2 = buttons[1];
+        numeric_to_shortest_final_path(button1, button2, 25)
+    }).sum()
+}
+
+fn sequence_numeric_part(sequence: &str) -> usize {
+    sequence[0..3].parse::<usize>().expect("invalid numeric sequence")
+}
+
+pub fn sequence_to_complexity_part1(sequence: String) -> usize {
+    let numeric_value = sequence_numeric_part(&sequence);
+    let len = numeric_sequence_to_final_sequence_length_part1(sequence);
+    len * numeric_value
+}
+
+pub fn sequence_to_complexity_part2(sequence: String) -> usize {
+    let numeric_value = sequence_numeric_part(&sequence);
+    let len = numeric_sequence_to_final_sequence_length_part2(sequence);
+    len * numeric_value
+}
+
+// door codes are always three digits followed by `A`
+pub fn read_sequences(input: &str) -> Result<Vec<String>, ParseError> {
+    parse_lines(input, |cursor| {
+        let code = cursor.rest();
+        for _ in 0..3 {
+            match cursor.peek() {
+                Some(c) if c.is_ascii_digit() => cursor.next_char(),
+                _ => return Err(cursor.error("expected digit")),
+            };
+        }
+        cursor.expect("A")?;
+        Ok(code.to_string())
+    })
+}
+
+pub struct DoorCodes {
+    sequences: Vec<String>,
+}
+
+impl Solution for DoorCodes {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self { sequences: read_sequences(input)? })
+    }
+
+    fn part1(&self) -> Answer {
+        self.sequences.iter().cloned().map(sequence_to_complexity_part1).sum::<usize>().into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.sequences.iter().cloned().map(sequence_to_complexity_part2).sum::<usize>().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{numeric_sequence_to_final_sequence_length_part1, read_sequences, sequence_to_complexity_part1};
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../input/day-21-test");
+        let sequences = read_sequences(input).unwrap();
+        let sum: usize = sequences.into_iter().map(sequence_to_complexity_part1).sum();
+        assert_eq!(sum, 126384);
+    }
+
+    #[test]
+    fn test_part1_final_sequence() {
+        let input = include_str!("../../input/day-21-test");
+        let sequences = read_sequences(input).unwrap();
+        let final_sequence0 = numeric_sequence_to_final_sequence_length_part1(sequences[0].clone());
+        println!("{}", final_sequence0);
+        assert_eq!(final_sequence0, 68);
+    }
+    
+    #[test]
+    fn test_part2() {
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let error = read_sequences("029A\n98A\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, col 3: expected digit");
+        let error = read_sequences("029A\n980B\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, col 4: expected `A`");
+        let error = read_sequences("029A\n980AA\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, col 5: unexpected `A`");
+    }
+}
\ No newline at end of file
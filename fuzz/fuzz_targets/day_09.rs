@@ -0,0 +1,9 @@
+#![no_main]
+
+use common::Solution;
+use libfuzzer_sys::fuzz_target;
+
+// disk maps, where anything but digits or a map without blocks must be an error
+fuzz_target!(|input: &str| {
+    let _ = day_09::FileSystem::parse(input);
+});
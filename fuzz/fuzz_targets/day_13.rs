@@ -0,0 +1,9 @@
+#![no_main]
+
+use common::Solution;
+use libfuzzer_sys::fuzz_target;
+
+// claw machines, three labelled lines each, with numbers of any length
+fuzz_target!(|input: &str| {
+    let _ = day_13::Machines::parse(input);
+});
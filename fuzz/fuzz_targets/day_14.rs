@@ -0,0 +1,9 @@
+#![no_main]
+
+use common::Solution;
+use libfuzzer_sys::fuzz_target;
+
+// robot lines `p=x,y v=dx,dy`, whose positions must be inside the room
+fuzz_target!(|input: &str| {
+    let _ = day_14::Robots::parse(input);
+});
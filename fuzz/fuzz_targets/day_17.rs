@@ -0,0 +1,9 @@
+#![no_main]
+
+use common::Solution;
+use libfuzzer_sys::fuzz_target;
+
+// register lines and a program, whose numbers must be 3 bits and operands not reserved
+fuzz_target!(|input: &str| {
+    let _ = day_17::Computer::parse(input);
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use common::Solution;
+use libfuzzer_sys::fuzz_target;
+
+// towels on the first line, then one pattern per line after a blank one
+fuzz_target!(|input: &str| {
+    let _ = day_19::Onsen::parse(input);
+});
@@ -0,0 +1,13 @@
+use rand::{seq::IndexedRandom, RngExt};
+
+use crate::Random;
+
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let left: Vec<u32> = (0..size).map(|_| rng.random_range(10000..100000)).collect();
+    // a third of the right list comes from the left one, otherwise the similarity would always be 0
+    let right: Vec<u32> = (0..size).map(|_| match rng.random_bool(0.3) {
+        true => *left.choose(rng).unwrap(),
+        false => rng.random_range(10000..100000),
+    }).collect();
+    left.iter().zip(&right).map(|(left, right)| format!("{left}   {right}\n")).collect()
+}
@@ -0,0 +1,24 @@
+use rand::RngExt;
+
+use crate::Random;
+
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    (0..size).map(|_| {
+        let length = rng.random_range(5..=8);
+        let increasing = rng.random_bool(0.5);
+        let mut level: i32 = if increasing { rng.random_range(1..=20) } else { rng.random_range(70..=90) };
+        let mut levels = vec![level];
+        for _ in 1..length {
+            let step = rng.random_range(1..=3);
+            level += if increasing { step } else { -step };
+            levels.push(level);
+        }
+        // about half of the reports get a bad level somewhere, which the dampener may or may not fix
+        if rng.random_bool(0.5) {
+            let index = rng.random_range(0..length);
+            levels[index] = rng.random_range(1..=99);
+        }
+        let levels: Vec<String> = levels.iter().map(|level| level.to_string()).collect();
+        format!("{}\n", levels.join(" "))
+    }).collect()
+}
@@ -0,0 +1,41 @@
+use rand::{seq::IndexedRandom, RngExt};
+
+use crate::Random;
+
+const JUNK: &[char] = &['!', '@', '#', '$', '%', '^', '&', '*', '(', ')', '[', ']', '{', '}', '<', '>', '+', '-', '?', '\'', ',', ' ', '~', ';', ':', 'm', 'u', 'l', 'd', 'o', 'w', 'h', 'y', 'f', 's', '0', '7'];
+
+fn mul(rng: &mut Random) -> String {
+    format!("mul({},{})", rng.random_range(1..1000), rng.random_range(1..1000))
+}
+
+/// Instructions lost in junk, with a few that are almost instructions. About six lines, like the real
+/// input.
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let line_length = size.div_ceil(6);
+    let mut memory = String::new();
+    for index in 0..size {
+        let instruction = match rng.random_range(0..10) {
+            0 => "do()".to_string(),
+            1 => "don't()".to_string(),
+            2 => {
+                let valid = mul(rng);
+                match rng.random_range(0..4) {
+                    0 => valid.replace(')', "]"),
+                    1 => valid.replace(',', " , "),
+                    2 => valid.replace("mul", "mul "),
+                    _ => format!("mul({},{})", rng.random_range(1000..10000), rng.random_range(1..1000)),
+                }
+            },
+            _ => mul(rng),
+        };
+        memory.push_str(&instruction);
+        (0..rng.random_range(0..8)).for_each(|_| memory.push(*JUNK.choose(rng).unwrap()));
+        if (index + 1) % line_length == 0 {
+            memory.push('\n');
+        }
+    }
+    if !memory.ends_with('\n') {
+        memory.push('\n');
+    }
+    memory
+}
@@ -0,0 +1,9 @@
+use rand::seq::IndexedRandom;
+
+use crate::{render, Random};
+
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let letters = ['X', 'M', 'A', 'S'];
+    let rows: Vec<Vec<char>> = (0..size).map(|_| (0..size).map(|_| *letters.choose(rng).unwrap()).collect()).collect();
+    render(&rows)
+}
@@ -0,0 +1,34 @@
+use rand::{seq::{IndexedRandom, SliceRandom}, RngExt};
+
+use crate::Random;
+
+const PAGES: usize = 49;
+
+/// The pages follow a hidden order, and like in the real input there's a rule for every pair of pages.
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let mut order: Vec<u32> = (10..100).collect();
+    order.shuffle(rng);
+    order.truncate(PAGES);
+
+    let mut rules: Vec<(u32, u32)> = (0..PAGES).flat_map(|i| (i + 1..PAGES).map(move |j| (i, j)))
+        .map(|(i, j)| (order[i], order[j]))
+        .collect();
+    rules.shuffle(rng);
+    let mut input: String = rules.iter().map(|(before, after)| format!("{before}|{after}\n")).collect();
+    input.push('\n');
+
+    let lengths: Vec<usize> = (5..=23).step_by(2).collect();
+    for _ in 0..size {
+        let mut pages: Vec<usize> = (0..PAGES).collect();
+        pages.shuffle(rng);
+        pages.truncate(*lengths.choose(rng).unwrap());
+        // about half of the updates are already in the right order
+        if rng.random_bool(0.5) {
+            pages.sort();
+        }
+        let pages: Vec<String> = pages.iter().map(|&page| order[page].to_string()).collect();
+        input.push_str(&pages.join(","));
+        input.push('\n');
+    }
+    input
+}
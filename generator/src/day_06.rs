@@ -0,0 +1,41 @@
+use std::collections::HashSet;
+
+use rand::RngExt;
+
+use crate::{render, Random};
+
+/// Whether the guard walks off the map instead of going around in circles.
+fn escapes(obstacles: &[Vec<bool>], start: (usize, usize)) -> bool {
+    let size = obstacles.len() as isize;
+    let (mut x, mut y) = (start.0 as isize, start.1 as isize);
+    let (mut dx, mut dy) = (0, -1);
+    let mut seen = HashSet::new();
+    while seen.insert((x, y, dx, dy)) {
+        let (nx, ny) = (x + dx, y + dy);
+        if nx < 0 || ny < 0 || nx >= size || ny >= size {
+            return true;
+        }
+        if obstacles[ny as usize][nx as usize] {
+            (dx, dy) = (-dy, dx);
+        } else {
+            (x, y) = (nx, ny);
+        }
+    }
+    false
+}
+
+/// Part 1 never ends if the guard is stuck in a loop, so maps are drawn until one lets the guard out.
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    loop {
+        let obstacles: Vec<Vec<bool>> = (0..size).map(|_| (0..size).map(|_| rng.random_bool(0.05)).collect()).collect();
+        let start = (rng.random_range(0..size), rng.random_range(0..size));
+        if obstacles[start.1][start.0] || !escapes(&obstacles, start) {
+            continue;
+        }
+        let mut rows: Vec<Vec<char>> = obstacles.iter()
+            .map(|row| row.iter().map(|&obstacle| if obstacle { '#' } else { '.' }).collect())
+            .collect();
+        rows[start.1][start.0] = '^';
+        return render(&rows);
+    }
+}
@@ -0,0 +1,48 @@
+use rand::RngExt;
+
+use crate::Random;
+
+// concatenating every number must still fit in a u64
+const MAX_DIGITS: usize = 16;
+
+fn number(rng: &mut Random) -> u64 {
+    match rng.random_range(0..10) {
+        0 => rng.random_range(100..1000),
+        1..=2 => rng.random_range(10..100),
+        _ => rng.random_range(1..10),
+    }
+}
+
+fn concat(a: u64, b: u64) -> u64 {
+    a * 10u64.pow(b.to_string().len() as u32) + b
+}
+
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    (0..size).map(|_| {
+        let count = rng.random_range(2..=10);
+        let mut numbers = vec![];
+        let mut digits = 0;
+        while numbers.len() < count {
+            let number = number(rng);
+            digits += number.to_string().len();
+            if digits > MAX_DIGITS {
+                break;
+            }
+            numbers.push(number);
+        }
+        let mut result = numbers[0];
+        numbers[1..].iter().for_each(|&number| {
+            result = match rng.random_range(0..3) {
+                0 => result + number,
+                1 => result * number,
+                _ => concat(result, number),
+            };
+        });
+        // some equations can't be solved
+        if rng.random_bool(0.4) {
+            result += rng.random_range(1..1000);
+        }
+        let numbers: Vec<String> = numbers.iter().map(|number| number.to_string()).collect();
+        format!("{result}: {}\n", numbers.join(" "))
+    }).collect()
+}
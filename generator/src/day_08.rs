@@ -0,0 +1,12 @@
+use rand::{seq::IndexedRandom, RngExt};
+
+use crate::{render, Random};
+
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let frequencies: Vec<char> = ('0'..='9').chain('a'..='z').chain('A'..='Z').collect();
+    let rows: Vec<Vec<char>> = (0..size).map(|_| (0..size).map(|_| match rng.random_bool(0.04) {
+        true => *frequencies.choose(rng).unwrap(),
+        false => '.',
+    }).collect()).collect();
+    render(&rows)
+}
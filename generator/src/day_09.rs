@@ -0,0 +1,15 @@
+use rand::RngExt;
+
+use crate::Random;
+
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let mut disk_map = String::with_capacity(2 * size);
+    for file in 0..size {
+        disk_map.push(char::from_digit(rng.random_range(1..=9), 10).unwrap());
+        if file + 1 < size {
+            disk_map.push(char::from_digit(rng.random_range(0..=9), 10).unwrap());
+        }
+    }
+    disk_map.push('\n');
+    disk_map
+}
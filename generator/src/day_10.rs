@@ -0,0 +1,17 @@
+use rand::RngExt;
+
+use crate::{render, Random};
+
+/// Hills going down from 9 at their top, so that there are trails to find, with some noise on them.
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let tops: Vec<(usize, usize)> = (0..size * size / 100 + 1).map(|_| (rng.random_range(0..size), rng.random_range(0..size))).collect();
+    let rows: Vec<Vec<char>> = (0..size).map(|y| (0..size).map(|x| {
+        let distance = tops.iter().map(|&(top_x, top_y)| top_x.abs_diff(x) + top_y.abs_diff(y)).min().unwrap();
+        let height = match rng.random_bool(0.1) {
+            true => rng.random_range(0..=9),
+            false => 9 - distance.min(9) as u32,
+        };
+        char::from_digit(height, 10).unwrap()
+    }).collect()).collect();
+    render(&rows)
+}
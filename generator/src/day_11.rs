@@ -0,0 +1,11 @@
+use rand::RngExt;
+
+use crate::Random;
+
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let stones: Vec<String> = (0..size).map(|_| match rng.random_bool(0.25) {
+        true => rng.random_range(0..10),
+        false => rng.random_range(10..1_000_000),
+    }.to_string()).collect();
+    format!("{}\n", stones.join(" "))
+}
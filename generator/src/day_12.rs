@@ -0,0 +1,23 @@
+use rand::RngExt;
+
+use crate::{render, Random};
+
+/// Plots mostly copy a neighbour, which grows regions of all shapes.
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let mut rows: Vec<Vec<char>> = vec![];
+    for y in 0..size {
+        let mut row: Vec<char> = vec![];
+        for x in 0..size {
+            let plant = match (x, y, rng.random_range(0..20)) {
+                (_, _, 0) | (0, 0, _) => rng.random_range('A'..='Z'),
+                (0, _, _) => rows[y - 1][x],
+                (_, 0, _) => row[x - 1],
+                (_, _, choice) if choice % 2 == 0 => rows[y - 1][x],
+                _ => row[x - 1],
+            };
+            row.push(plant);
+        }
+        rows.push(row);
+    }
+    render(&rows)
+}
@@ -0,0 +1,19 @@
+use rand::RngExt;
+
+use crate::Random;
+
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let machines: Vec<String> = (0..size).map(|_| {
+        let (a_x, a_y, b_x, b_y) = (rng.random_range(10..100), rng.random_range(10..100), rng.random_range(10..100), rng.random_range(10..100));
+        // about half of the prizes can be won
+        let (prize_x, prize_y): (u32, u32) = match rng.random_bool(0.5) {
+            true => {
+                let (a, b) = (rng.random_range(0..=100), rng.random_range(0..=100));
+                (a * a_x + b * b_x, a * a_y + b * b_y)
+            },
+            false => (rng.random_range(1000..20000), rng.random_range(1000..20000)),
+        };
+        format!("Button A: X+{a_x}, Y+{a_y}\nButton B: X+{b_x}, Y+{b_y}\nPrize: X={prize_x}, Y={prize_y}\n")
+    }).collect();
+    machines.join("\n")
+}
@@ -0,0 +1,27 @@
+use rand::{seq::SliceRandom, RngExt};
+
+use crate::Random;
+
+const WIDTH: i32 = 101;
+const HEIGHT: i32 = 103;
+
+/// Robots picked at random almost never all stand on different tiles, which is what part 2 looks for.
+/// So they're placed on different tiles at some random second, then moved back to where they start.
+/// The room size is deduced from the positions, so the first robot starts in the far corner.
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let seconds = rng.random_range(0..WIDTH * HEIGHT);
+    let mut tiles: Vec<(i32, i32)> = (0..HEIGHT).flat_map(|y| (0..WIDTH).map(move |x| (x, y))).collect();
+    tiles.shuffle(rng);
+
+    let corner_velocity = (rng.random_range(-100..=100), rng.random_range(-100..=100));
+    let corner_tile = ((WIDTH - 1 + corner_velocity.0 * seconds).rem_euclid(WIDTH), (HEIGHT - 1 + corner_velocity.1 * seconds).rem_euclid(HEIGHT));
+    tiles.retain(|&tile| tile != corner_tile);
+
+    let mut input = format!("p={},{} v={},{}\n", WIDTH - 1, HEIGHT - 1, corner_velocity.0, corner_velocity.1);
+    tiles.iter().take(size - 1).for_each(|&(x, y)| {
+        let (vx, vy) = (rng.random_range(-100..=100), rng.random_range(-100..=100));
+        let (start_x, start_y) = ((x - vx * seconds).rem_euclid(WIDTH), (y - vy * seconds).rem_euclid(HEIGHT));
+        input.push_str(&format!("p={start_x},{start_y} v={vx},{vy}\n"));
+    });
+    input
+}
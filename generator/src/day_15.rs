@@ -0,0 +1,25 @@
+use rand::{seq::IndexedRandom, RngExt};
+
+use crate::{render, Random};
+
+const MOVES_PER_LINE: usize = 1000;
+
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let size = size.max(4);
+    let mut rows: Vec<Vec<char>> = (0..size).map(|y| (0..size).map(|x| {
+        if x == 0 || y == 0 || x == size - 1 || y == size - 1 {
+            return '#';
+        }
+        match rng.random_range(0..10) {
+            0 => '#',
+            1..=2 => 'O',
+            _ => '.',
+        }
+    }).collect()).collect();
+    let (x, y) = (rng.random_range(1..size - 1), rng.random_range(1..size - 1));
+    rows[y][x] = '@';
+
+    let moves: Vec<char> = (0..size * size * 8).map(|_| *['^', '>', 'v', '<'].choose(rng).unwrap()).collect();
+    let moves: String = moves.chunks(MOVES_PER_LINE).map(|line| line.iter().chain(['\n'].iter()).collect::<String>()).collect();
+    format!("{}\n{moves}", render(&rows))
+}
@@ -0,0 +1,13 @@
+use crate::{maze, render, Random};
+
+/// A maze with a few loops, so that there's sometimes more than one best path. The start is in the
+/// bottom left corner and the end in the top right one, like in the real input.
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let mut open = maze::carve(rng, size);
+    let size = open.len();
+    maze::add_loops(rng, &mut open, size * size / 400);
+    let mut rows: Vec<Vec<char>> = open.iter().map(|row| row.iter().map(|&open| if open { '.' } else { '#' }).collect()).collect();
+    rows[size - 2][1] = 'S';
+    rows[1][size - 2] = 'E';
+    render(&rows)
+}
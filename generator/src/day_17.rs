@@ -0,0 +1,25 @@
+use day_17::Computer;
+use rand::{seq::IndexedRandom, RngExt};
+
+use crate::Random;
+
+/// Programs shaped like the real ones: each round prints a few scrambled bits of A, then shifts A by
+/// 3 bits until it's 0, so `size` is the number of outputs. Most such programs never print
+/// themselves, so they are drawn again until part 2 has an answer.
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let size = size.min(21) as u32;
+    let register_a = rng.random_range(8u64.pow(size - 1)..8u64.pow(size));
+    loop {
+        let (x, y, z) = (rng.random_range(0..8), rng.random_range(0..8), rng.random_range(0..8));
+        let programs = [
+            [2, 4, 1, x, 7, 5, 1, y, 4, z, 5, 5, 0, 3, 3, 0],
+            [2, 4, 1, x, 7, 5, 4, z, 1, y, 5, 5, 0, 3, 3, 0],
+            [2, 4, 1, x, 7, 5, 0, 3, 4, z, 1, y, 5, 5, 3, 0],
+        ];
+        let program: Vec<String> = programs.choose(rng).unwrap().iter().map(|value: &u8| value.to_string()).collect();
+        let input = format!("Register A: {register_a}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n", program.join(","));
+        if Computer::try_from(input.as_str()).unwrap().find_register().is_some() {
+            return input;
+        }
+    }
+}
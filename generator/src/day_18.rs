@@ -0,0 +1,62 @@
+use std::collections::VecDeque;
+
+use rand::seq::SliceRandom;
+
+use crate::Random;
+
+const SIZE: usize = 71;
+// part 1 looks at that many bytes, and needs a way out after they fell
+const FALLEN_BYTES_PART_1: usize = 1024;
+
+fn has_exit(bytes: &[(usize, usize)]) -> bool {
+    let mut blocked = vec![vec![false; SIZE]; SIZE];
+    bytes.iter().for_each(|&(x, y)| blocked[y][x] = true);
+    let mut queue = VecDeque::from([(0, 0)]);
+    blocked[0][0] = true;
+    while let Some((x, y)) = queue.pop_front() {
+        if (x, y) == (SIZE - 1, SIZE - 1) {
+            return true;
+        }
+        let neighbors = [(x.wrapping_sub(1), y), (x + 1, y), (x, y.wrapping_sub(1)), (x, y + 1)];
+        for (nx, ny) in neighbors {
+            if nx < SIZE && ny < SIZE && !blocked[ny][nx] {
+                blocked[ny][nx] = true;
+                queue.push_back((nx, ny));
+            }
+        }
+    }
+    false
+}
+
+/// Index of the first byte that cuts the exit off. Everything falls eventually, so there is one.
+fn first_blocking_byte(bytes: &[(usize, usize)]) -> usize {
+    let (mut low, mut high) = (0, bytes.len());
+    while low < high {
+        let middle = (low + high) / 2;
+        match has_exit(&bytes[..=middle]) {
+            true => low = middle + 1,
+            false => high = middle,
+        }
+    }
+    low
+}
+
+/// At least `size` bytes, and in any case enough of them to cut the exit off, which part 2 relies on.
+/// Bytes falling at random quickly wall the start in, so the ones cutting the exit off too early are
+/// pushed back until the first 1024 bytes leave a way out, like in the real input.
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let mut bytes: Vec<(usize, usize)> = (0..SIZE).flat_map(|y| (0..SIZE).map(move |x| (x, y)))
+        .filter(|&position| position != (0, 0) && position != (SIZE - 1, SIZE - 1))
+        .collect();
+    bytes.shuffle(rng);
+    let blocking = loop {
+        let blocking = first_blocking_byte(&bytes);
+        if blocking >= FALLEN_BYTES_PART_1 {
+            break blocking;
+        }
+        let byte = bytes.remove(blocking);
+        bytes.push(byte);
+    };
+    bytes.truncate(size.max(blocking + 1));
+    bytes.iter().map(|(x, y)| format!("{x},{y}\n")).collect()
+}
@@ -0,0 +1,34 @@
+use std::collections::BTreeSet;
+
+use rand::{seq::IndexedRandom, RngExt};
+
+use crate::Random;
+
+/// One of the colors is in no towel, so the patterns with it can't be made. It shows up early in them:
+/// part 1 doesn't remember dead ends, and would take forever on a long pattern that fails at the end.
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let mut colors = vec!['w', 'u', 'b', 'r', 'g'];
+    let missing = colors.remove(rng.random_range(0..colors.len()));
+
+    let mut towels = BTreeSet::new();
+    while towels.len() < size.min(400) {
+        let length = rng.random_range(1..=8);
+        towels.insert((0..length).map(|_| *colors.choose(rng).unwrap()).collect::<String>());
+    }
+    let towels: Vec<String> = towels.into_iter().collect();
+    let mut input = format!("{}\n\n", towels.join(", "));
+
+    for _ in 0..size {
+        let length = rng.random_range(20..=60);
+        let mut pattern = String::new();
+        while pattern.len() < length {
+            pattern.push_str(towels.choose(rng).unwrap());
+        }
+        if rng.random_bool(0.3) {
+            pattern.insert(rng.random_range(0..10.min(pattern.len())), missing);
+        }
+        input.push_str(&pattern);
+        input.push('\n');
+    }
+    input
+}
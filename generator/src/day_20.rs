@@ -0,0 +1,14 @@
+use crate::{maze, render, Random};
+
+/// A single track winding through walls: the way from the start to the end of a maze, with the rest of
+/// the maze filled in.
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    let open = maze::carve(rng, size);
+    let size = open.len();
+    let (start, end) = ((1, size - 2), (size - 2, 1));
+    let mut rows = vec![vec!['#'; size]; size];
+    maze::path(&open, start, end).into_iter().for_each(|(x, y)| rows[y][x] = '.');
+    rows[start.1][start.0] = 'S';
+    rows[end.1][end.0] = 'E';
+    render(&rows)
+}
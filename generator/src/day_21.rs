@@ -0,0 +1,7 @@
+use rand::RngExt;
+
+use crate::Random;
+
+pub fn generate(rng: &mut Random, size: usize) -> String {
+    (0..size).map(|_| format!("{:03}A\n", rng.random_range(0..1000))).collect()
+}
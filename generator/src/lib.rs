@@ -0,0 +1,99 @@
+//! Random puzzle inputs that the days accept, for stress tests and benchmarks.
+//!
+//! The same day, seed and size always give the same input, as long as `rand` stays on the same minor
+//! version: the generator is one of its portable ones. So a generated input can be shared as a command
+//! line instead of a file.
+
+mod day_01;
+mod day_02;
+mod day_03;
+mod day_04;
+mod day_05;
+mod day_06;
+mod day_07;
+mod day_08;
+mod day_09;
+mod day_10;
+mod day_11;
+mod day_12;
+mod day_13;
+mod day_14;
+mod day_15;
+mod day_16;
+mod day_17;
+mod day_18;
+mod day_19;
+mod day_20;
+mod day_21;
+mod maze;
+
+use rand::{rngs::Xoshiro256PlusPlus, SeedableRng};
+
+type Random = Xoshiro256PlusPlus;
+
+pub struct Generator {
+    pub day: u8,
+    /// What `size` counts for this day
+    pub size_unit: &'static str,
+    /// About the size of the real inputs
+    pub default_size: usize,
+    generate: fn(&mut Random, usize) -> String,
+}
+
+impl Generator {
+    /// Panics if `size` is 0, no day has an empty input.
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        assert!(size > 0, "size must be at least 1");
+        let mut rng = Random::seed_from_u64(seed);
+        (self.generate)(&mut rng, size)
+    }
+}
+
+pub fn find_generator(day: u8) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|generator| generator.day == day)
+}
+
+pub const GENERATORS: [Generator; 21] = [
+    Generator { day: 1, size_unit: "pairs of locations", default_size: 1000, generate: day_01::generate },
+    Generator { day: 2, size_unit: "reports", default_size: 1000, generate: day_02::generate },
+    Generator { day: 3, size_unit: "instructions", default_size: 700, generate: day_03::generate },
+    Generator { day: 4, size_unit: "letters per side", default_size: 140, generate: day_04::generate },
+    Generator { day: 5, size_unit: "updates", default_size: 200, generate: day_05::generate },
+    Generator { day: 6, size_unit: "cells per side", default_size: 130, generate: day_06::generate },
+    Generator { day: 7, size_unit: "equations", default_size: 850, generate: day_07::generate },
+    Generator { day: 8, size_unit: "cells per side", default_size: 50, generate: day_08::generate },
+    Generator { day: 9, size_unit: "files", default_size: 10000, generate: day_09::generate },
+    Generator { day: 10, size_unit: "cells per side", default_size: 50, generate: day_10::generate },
+    Generator { day: 11, size_unit: "stones", default_size: 8, generate: day_11::generate },
+    Generator { day: 12, size_unit: "plots per side", default_size: 140, generate: day_12::generate },
+    Generator { day: 13, size_unit: "machines", default_size: 320, generate: day_13::generate },
+    Generator { day: 14, size_unit: "robots", default_size: 500, generate: day_14::generate },
+    Generator { day: 15, size_unit: "cells per side", default_size: 50, generate: day_15::generate },
+    Generator { day: 16, size_unit: "cells per side", default_size: 141, generate: day_16::generate },
+    Generator { day: 17, size_unit: "outputs", default_size: 10, generate: day_17::generate },
+    Generator { day: 18, size_unit: "bytes", default_size: 3450, generate: day_18::generate },
+    Generator { day: 19, size_unit: "patterns", default_size: 400, generate: day_19::generate },
+    Generator { day: 20, size_unit: "cells per side", default_size: 141, generate: day_20::generate },
+    Generator { day: 21, size_unit: "codes", default_size: 5, generate: day_21::generate },
+];
+
+/// Draws a character map, one line per row.
+fn render(rows: &[Vec<char>]) -> String {
+    rows.iter().map(|row| row.iter().chain(['\n'].iter()).collect::<String>()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{find_generator, GENERATORS};
+
+    #[test]
+    fn test_same_seed_same_input() {
+        GENERATORS.iter().for_each(|generator| {
+            let input = generator.generate(7, 10);
+            assert!(!input.is_empty(), "day {}", generator.day);
+            assert_eq!(generator.generate(7, 10), input, "day {}", generator.day);
+        });
+        let generator = find_generator(2).unwrap();
+        assert_ne!(generator.generate(1, 10), generator.generate(2, 10));
+    }
+}
@@ -0,0 +1,71 @@
+use rand::{seq::SliceRandom, RngExt};
+
+use crate::Random;
+
+/// A perfect maze: walls on the border, and exactly one way between any two open cells. Open cells sit
+/// at odd coordinates, so `size` is rounded up to an odd number (at least 5).
+pub fn carve(rng: &mut Random, size: usize) -> Vec<Vec<bool>> {
+    let size = (size.max(5) - 1) / 2 * 2 + 1;
+    let mut open = vec![vec![false; size]; size];
+    let mut stack = vec![(1, 1)];
+    open[1][1] = true;
+    while let Some(&(x, y)) = stack.last() {
+        let mut directions = [(0isize, -2isize), (2, 0), (0, 2), (-2, 0)];
+        directions.shuffle(rng);
+        let next = directions.iter().map(|&(dx, dy)| (x as isize + dx, y as isize + dy)).find(|&(nx, ny)| {
+            nx > 0 && ny > 0 && (nx as usize) < size - 1 && (ny as usize) < size - 1 && !open[ny as usize][nx as usize]
+        });
+        match next {
+            Some((nx, ny)) => {
+                let (nx, ny) = (nx as usize, ny as usize);
+                open[(y + ny) / 2][(x + nx) / 2] = true;
+                open[ny][nx] = true;
+                stack.push((nx, ny));
+            },
+            None => {
+                stack.pop();
+            },
+        }
+    }
+    open
+}
+
+/// Knocks down `count` walls between two open cells, so that there's more than one way around.
+pub fn add_loops(rng: &mut Random, open: &mut [Vec<bool>], count: usize) {
+    let size = open.len();
+    for _ in 0..count {
+        let x = rng.random_range(1..size - 1);
+        let y = rng.random_range(1..size - 1);
+        let between_horizontal = open[y][x - 1] && open[y][x + 1];
+        let between_vertical = open[y - 1][x] && open[y + 1][x];
+        if between_horizontal != between_vertical {
+            open[y][x] = true;
+        }
+    }
+}
+
+/// The cells on the way from `start` to `end`, both included.
+pub fn path(open: &[Vec<bool>], start: (usize, usize), end: (usize, usize)) -> Vec<(usize, usize)> {
+    let size = open.len();
+    let mut previous = vec![vec![None; size]; size];
+    let mut queue = std::collections::VecDeque::from([start]);
+    previous[start.1][start.0] = Some(start);
+    while let Some((x, y)) = queue.pop_front() {
+        if (x, y) == end {
+            break;
+        }
+        for (nx, ny) in [(x, y - 1), (x + 1, y), (x, y + 1), (x - 1, y)] {
+            if open[ny][nx] && previous[ny][nx].is_none() {
+                previous[ny][nx] = Some((x, y));
+                queue.push_back((nx, ny));
+            }
+        }
+    }
+    let mut path = vec![end];
+    while *path.last().unwrap() != start {
+        let (x, y) = *path.last().unwrap();
+        path.push(previous[y][x].unwrap());
+    }
+    path.reverse();
+    path
+}
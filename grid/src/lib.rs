@@ -0,0 +1,222 @@
+pub mod position;
+
+use std::ops::{Index, IndexMut};
+use common::{parse::parse_grid, ParseError};
+
+pub use position::{Connectivity, Direction, Heading, Position};
+
+/// A rectangular map stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self where T: Clone {
+        Self { width, height, cells: vec![value; width * height] }
+    }
+
+    /// Panics if the rows don't all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        assert!(rows.iter().all(|row| row.len() == width), "grid rows must have the same length");
+        Self { width, height, cells: rows.into_iter().flatten().collect() }
+    }
+
+    /// Parses a character map, one cell per character.
+    pub fn parse(input: &str, parse_cell: impl FnMut(char) -> Result<T, ParseError>) -> Result<Self, ParseError> {
+        parse_grid(input, parse_cell).map(Self::from_rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.x < self.width && position.y < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position).then(|| &self.cells[position.y * self.width + position.x])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        match self.contains(position) {
+            true => Some(&mut self.cells[position.y * self.width + position.x]),
+            false => None,
+        }
+    }
+
+    /// Moves one cell in `direction`, `None` when that leaves the grid.
+    pub fn step(&self, position: Position, direction: Direction) -> Option<Position> {
+        position.step(direction).filter(|&position| self.contains(position))
+    }
+
+    /// Positions around `position` that are inside the grid.
+    pub fn neighbors(&self, position: Position, connectivity: Connectivity) -> impl Iterator<Item = Position> + '_ {
+        connectivity.offsets().iter()
+            .filter_map(move |&(dx, dy)| position.offset(dx, dy))
+            .filter(|&position| self.contains(position))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Position { x, y }))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Position> {
+        self.iter().find(|(_, cell)| predicate(cell)).map(|(position, _)| position)
+    }
+
+    /// Finds the one cell matching `predicate`, reporting where the input is wrong otherwise.
+    pub fn find_unique(&self, predicate: impl Fn(&T) -> bool, name: &str) -> Result<Position, ParseError> {
+        let mut found = self.iter().filter(|(_, cell)| predicate(cell)).map(|(position, _)| position);
+        let first = found.next()
+            .ok_or_else(|| ParseError::at(self.height + 1, 1, format!("unexpected end of input, no {name} found")))?;
+        match found.next() {
+            Some(Position { x, y }) => Err(ParseError::at(y + 1, x + 1, format!("found a second {name}"))),
+            None => Ok(first),
+        }
+    }
+
+    /// Several days walk around without bound checks, relying on the map being fenced by walls.
+    pub fn check_walled(&self, is_wall: impl Fn(&T) -> bool) -> Result<(), ParseError> {
+        let on_border = |position: &Position| {
+            position.x == 0 || position.y == 0 || position.x == self.width - 1 || position.y == self.height - 1
+        };
+        match self.iter().find(|(position, cell)| on_border(position) && !is_wall(cell)) {
+            Some((Position { x, y }, _)) => Err(ParseError::at(y + 1, x + 1, "expected a wall on the border of the map")),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks that `to` can be reached from `from` through the cells that aren't walls, for the days
+    /// that would look for a path forever otherwise.
+    pub fn check_reachable(&self, from: Position, to: Position, is_wall: impl Fn(&T) -> bool, names: (&str, &str)) -> Result<(), ParseError> {
+        let mut seen = Grid::new(self.width, self.height, false);
+        let mut stack = vec![from];
+        seen[from] = true;
+        while let Some(position) = stack.pop() {
+            if position == to {
+                return Ok(());
+            }
+            for neighbor in self.neighbors(position, Connectivity::Four) {
+                if !seen[neighbor] && !is_wall(&self[neighbor]) {
+                    seen[neighbor] = true;
+                    stack.push(neighbor);
+                }
+            }
+        }
+        Err(ParseError::at(from.y + 1, from.x + 1, format!("no way from the {} to the {}", names.0, names.1)))
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    /// Draws the grid back as a character map, the opposite of `parse`.
+    pub fn render(&self, to_char: impl Fn(&T) -> char) -> String {
+        let mut output = String::with_capacity((self.width + 1) * self.height);
+        self.rows().for_each(|row| {
+            output.extend(row.iter().map(&to_char));
+            output.push('\n');
+        });
+        output
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        assert!(self.contains(position), "{position:?} is outside of the grid");
+        &self.cells[position.y * self.width + position.x]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        assert!(self.contains(position), "{position:?} is outside of the grid");
+        &mut self.cells[position.y * self.width + position.x]
+    }
+}
+
+/// `grid[y][x]`, for code that's easier to read with raw coordinates.
+impl<T> Index<usize> for Grid<T> {
+    type Output = [T];
+
+    fn index(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::ParseError;
+    use crate::{Connectivity, Grid, Position};
+
+    fn digit(c: char) -> Result<u32, ParseError> {
+        c.to_digit(10).ok_or_else(|| ParseError::new("expected digit"))
+    }
+
+    #[test]
+    fn test_parse_and_render() {
+        let input = "123\n456\n";
+        let grid = Grid::parse(input, digit).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Position::new(2, 1)], 6);
+        assert_eq!(grid[1][0], 4);
+        assert_eq!(grid.get(Position::new(3, 0)), None);
+        assert_eq!(grid.render(|n| char::from_digit(*n, 10).unwrap()), input);
+        assert_eq!(Grid::parse("12\n4x\n", digit).unwrap_err().to_string(), "line 2, col 2: expected digit");
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(grid.neighbors(Position::new(0, 0), Connectivity::Four).collect::<Vec<_>>(), vec![Position::new(1, 0), Position::new(0, 1)]);
+        assert_eq!(grid.neighbors(Position::new(2, 2), Connectivity::Eight).count(), 3);
+        assert_eq!(grid.neighbors(Position::new(1, 1), Connectivity::Eight).count(), 8);
+    }
+
+    #[test]
+    fn test_walls_and_unique_cells() {
+        let grid = Grid::parse("###\n#S#\n#.S", Ok).unwrap();
+        assert_eq!(grid.check_walled(|c| *c == '#').unwrap_err().to_string(), "line 3, col 2: expected a wall on the border of the map");
+        assert_eq!(grid.find_unique(|c| *c == 'S', "start").unwrap_err().to_string(), "line 3, col 3: found a second start");
+        assert_eq!(grid.find_unique(|c| *c == 'E', "end").unwrap_err().to_string(), "line 4, col 1: unexpected end of input, no end found");
+        assert_eq!(grid.find_unique(|c| *c == '.', "floor"), Ok(Position::new(1, 2)));
+    }
+
+    #[test]
+    fn test_check_reachable() {
+        let grid = Grid::parse("#####\n#S#E#\n#...#\n#####", Ok).unwrap();
+        let names = ("start", "end");
+        assert_eq!(grid.check_reachable(Position::new(1, 1), Position::new(3, 1), |c| *c == '#', names), Ok(()));
+        let grid = Grid::parse("#####\n#S#E#\n#####", Ok).unwrap();
+        let error = grid.check_reachable(Position::new(1, 1), Position::new(3, 1), |c| *c == '#', names).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, col 2: no way from the start to the end");
+    }
+}
@@ -0,0 +1,163 @@
+use common::ParseError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Position {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Position {
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// `None` when moving would go past the top or the left edge. The other edges depend on the grid,
+    /// see `Grid::step`.
+    pub fn offset(&self, dx: isize, dy: isize) -> Option<Self> {
+        let x = self.x.checked_add_signed(dx)?;
+        let y = self.y.checked_add_signed(dy)?;
+        Some(Self { x, y })
+    }
+
+    pub fn step(&self, direction: Direction) -> Option<Self> {
+        let (dx, dy) = direction.offset();
+        self.offset(dx, dy)
+    }
+
+    pub fn manhattan_distance(&self, other: &Position) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+/// North is up, towards the first line of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    pub fn offset(&self) -> (isize, isize) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::East => (1, 0),
+            Direction::South => (0, 1),
+            Direction::West => (-1, 0),
+        }
+    }
+
+    pub fn turn_right(&self) -> Self {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn turn_left(&self) -> Self {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    pub fn opposite(&self) -> Self {
+        self.turn_right().turn_right()
+    }
+}
+
+/// Arrows as drawn in puzzle inputs.
+impl TryFrom<char> for Direction {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' => Ok(Direction::North),
+            '>' => Ok(Direction::East),
+            'v' => Ok(Direction::South),
+            '<' => Ok(Direction::West),
+            _ => Err(ParseError::new(format!("unexpected `{c}`, expected `^`, `>`, `v` or `<`"))),
+        }
+    }
+}
+
+/// The 8 ways out of a cell, diagonals included, clockwise from north like `Connectivity::Eight`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Heading {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Heading {
+    pub const ALL: [Heading; 8] = [
+        Heading::North, Heading::NorthEast, Heading::East, Heading::SouthEast,
+        Heading::South, Heading::SouthWest, Heading::West, Heading::NorthWest,
+    ];
+
+    pub fn offset(&self) -> (isize, isize) {
+        Connectivity::Eight.offsets()[*self as usize]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Up, down, left and right
+    Four,
+    /// Diagonals too
+    Eight,
+}
+
+impl Connectivity {
+    pub fn offsets(&self) -> &'static [(isize, isize)] {
+        match self {
+            Connectivity::Four => &[(0, -1), (1, 0), (0, 1), (-1, 0)],
+            // `Heading::offset` relies on this order
+            Connectivity::Eight => &[(0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1)],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::position::{Direction, Heading, Position};
+
+    #[test]
+    fn test_steps() {
+        let position = Position::new(1, 0);
+        assert_eq!(position.step(Direction::North), None);
+        assert_eq!(position.step(Direction::West), Some(Position::new(0, 0)));
+        assert_eq!(position.offset(2, 3), Some(Position::new(3, 3)));
+        assert_eq!(position.manhattan_distance(&Position::new(3, 3)), 5);
+    }
+
+    #[test]
+    fn test_turns() {
+        Direction::ALL.iter().for_each(|direction| {
+            assert_eq!(direction.turn_right().turn_left(), *direction);
+            assert_eq!(direction.opposite().opposite(), *direction);
+        });
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::try_from('<'), Ok(Direction::West));
+    }
+
+    #[test]
+    fn test_headings() {
+        // the straight headings go the same way as the directions
+        let straight = [Heading::North, Heading::East, Heading::South, Heading::West];
+        straight.iter().zip(Direction::ALL).for_each(|(heading, direction)| assert_eq!(heading.offset(), direction.offset()));
+        assert_eq!(Heading::SouthWest.offset(), (-1, 1));
+    }
+}